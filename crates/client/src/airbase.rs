@@ -1,7 +1,7 @@
 use std::fmt;
 
 use crate::jsonrpc::Client;
-use crate::{Error, Position};
+use crate::{Coalition, Error, Position, Warehouse};
 
 #[derive(Clone, Serialize)]
 pub struct Airbase {
@@ -31,6 +31,10 @@ impl Airbase {
             .ok_or_else(|| Error::NonExistent)
     }
 
+    pub fn id(&self) -> Result<u64, Error> {
+        self.request("airbaseID")
+    }
+
     pub fn exists(&self) -> Result<bool, Error> {
         self.client.request("airbaseExists", Some(&self))
     }
@@ -38,6 +42,140 @@ impl Airbase {
     pub fn position(&self) -> Result<Position, Error> {
         self.request("airbasePosition")
     }
+
+    pub fn coalition(&self) -> Result<Coalition, Error> {
+        self.request("airbaseCoalition")
+    }
+
+    pub fn category(&self) -> Result<AirbaseCategory, Error> {
+        self.request("airbaseCategory")
+    }
+
+    pub fn callsign(&self) -> Result<String, Error> {
+        self.request("airbaseCallsign")
+    }
+
+    /// The airbase's runways (airdromes only).
+    pub fn runways(&self) -> Result<Vec<Runway>, Error> {
+        self.request("airbaseRunways")
+    }
+
+    /// The airbase's parking spots, only the unoccupied ones if `available_only` is set.
+    pub fn parking(&self, available_only: bool) -> Result<Vec<ParkingSpot>, Error> {
+        #[derive(Serialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Params<'a> {
+            name: &'a str,
+            available_only: bool,
+        }
+
+        self.client
+            .request::<_, Option<Vec<ParkingSpot>>>(
+                "airbaseParking",
+                Some(Params {
+                    name: &self.name,
+                    available_only,
+                }),
+            )?
+            .ok_or(Error::NonExistent)
+    }
+
+    /// The airbase's warehouse, which stores its aircraft, weapons and fuel.
+    pub fn warehouse(&self) -> Warehouse {
+        Warehouse::new(self.client.clone(), self.name.as_str())
+    }
+
+    /// Hands the airbase over to the given `coalition`. Unless auto capture is disabled (see
+    /// [Airbase::auto_capture]), units nearby can capture it back.
+    pub fn set_coalition(&self, coalition: Coalition) -> Result<(), Error> {
+        #[derive(Serialize)]
+        struct Params<'a> {
+            name: &'a str,
+            coalition: Coalition,
+        }
+
+        self.client.notification(
+            "airbaseSetCoalition",
+            Some(Params {
+                name: &self.name,
+                coalition,
+            }),
+        )
+    }
+
+    /// Enables or disables whether the airbase can be captured by units nearby.
+    pub fn auto_capture(&self, enabled: bool) -> Result<(), Error> {
+        #[derive(Serialize)]
+        struct Params<'a> {
+            name: &'a str,
+            enabled: bool,
+        }
+
+        self.client.notification(
+            "airbaseAutoCapture",
+            Some(Params {
+                name: &self.name,
+                enabled,
+            }),
+        )
+    }
+}
+
+enum_number!(AirbaseCategory {
+    Airdrome = 0,
+    Helipad = 1,
+    Ship = 2,
+});
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Runway {
+    /// The runway's designation, e.g. `04`.
+    pub name: String,
+    /// The runway's course in radians.
+    pub course: f64,
+    /// The runway's length in m.
+    pub length: f64,
+    /// The runway's width in m.
+    pub width: f64,
+    pub position: Position,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ParkingSpot {
+    /// The parking spot's number.
+    pub index: u32,
+    pub terminal_type: TerminalType,
+    pub position: Position,
+    /// Whether an aircraft is currently parked at the spot.
+    pub occupied: bool,
+    /// The distance to the runway in m.
+    pub distance_to_runway: f64,
+}
+
+/// The kind of aircraft a parking spot can be used by.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(from = "u32")]
+pub enum TerminalType {
+    Runway,
+    HelicopterOnly,
+    HardenedAirShelter,
+    AirplaneOnly,
+    OpenAir,
+    Other(u32),
+}
+
+impl From<u32> for TerminalType {
+    fn from(value: u32) -> Self {
+        match value {
+            16 => TerminalType::Runway,
+            40 => TerminalType::HelicopterOnly,
+            68 => TerminalType::HardenedAirShelter,
+            72 => TerminalType::AirplaneOnly,
+            104 => TerminalType::OpenAir,
+            other => TerminalType::Other(other),
+        }
+    }
 }
 
 impl fmt::Debug for Airbase {
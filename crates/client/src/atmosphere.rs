@@ -0,0 +1,121 @@
+use crate::{Speed, Vector};
+
+/// A temperature in K.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Deserialize)]
+pub struct Temperature(f64);
+
+impl Temperature {
+    pub fn kelvin(kelvin: f64) -> Self {
+        Temperature(kelvin)
+    }
+
+    pub fn celsius(celsius: f64) -> Self {
+        Temperature(celsius + 273.15)
+    }
+
+    pub fn as_kelvin(self) -> f64 {
+        self.0
+    }
+
+    pub fn as_celsius(self) -> f64 {
+        self.0 - 273.15
+    }
+
+    pub fn as_fahrenheit(self) -> f64 {
+        self.as_celsius() * 1.8 + 32.0
+    }
+}
+
+/// A pressure in Pa.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Deserialize)]
+pub struct Pressure(f64);
+
+impl Pressure {
+    pub fn pascal(pascal: f64) -> Self {
+        Pressure(pascal)
+    }
+
+    pub fn hpa(hpa: f64) -> Self {
+        Pressure(hpa * 100.0)
+    }
+
+    pub fn as_pascal(self) -> f64 {
+        self.0
+    }
+
+    pub fn as_hpa(self) -> f64 {
+        self.0 / 100.0
+    }
+
+    pub fn as_inhg(self) -> f64 {
+        self.0 / 3386.389
+    }
+
+    pub fn as_mmhg(self) -> f64 {
+        self.0 / 133.322_4
+    }
+}
+
+/// The temperature and pressure at a point (see [crate::Client::temperature_and_pressure_at]).
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+pub struct TemperatureAndPressure {
+    pub temperature: Temperature,
+    pub pressure: Pressure,
+}
+
+/// A wind given by the direction it is blowing from and its speed, as used for e.g. weather
+/// reports.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Wind {
+    /// The direction in degrees (0-360, relative to the map's north) the wind is blowing from.
+    pub direction: f64,
+    pub speed: Speed,
+}
+
+impl Wind {
+    /// Converts a wind vector (as returned by [crate::Client::wind_at]) into the direction the
+    /// wind is blowing from and its horizontal speed.
+    pub fn from_vector(wind: &Vector) -> Self {
+        let speed = (wind.x.powi(2) + wind.z.powi(2)).sqrt();
+        // the vector points towards where the wind is blowing to
+        let direction = (wind.z.atan2(wind.x).to_degrees() + 180.0).rem_euclid(360.0);
+
+        Wind {
+            direction,
+            speed: Speed::mps(speed),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wind(x: f64, z: f64) -> Wind {
+        Wind::from_vector(&Vector { x, y: 0.0, z })
+    }
+
+    #[test]
+    fn wind_from_vector() {
+        // blowing towards the south, i.e. from the north
+        let w = wind(-10.0, 0.0);
+        assert!(w.direction.abs() < 1e-9 || (w.direction - 360.0).abs() < 1e-9);
+        assert!((w.speed.as_mps() - 10.0).abs() < 1e-9);
+
+        // blowing towards the east, i.e. from the west
+        assert!((wind(0.0, 5.0).direction - 270.0).abs() < 1e-9);
+        // blowing towards the south west, i.e. from the north east
+        let w = wind(-3.0, -3.0);
+        assert!((w.direction - 45.0).abs() < 1e-9);
+        assert!((w.speed.as_mps() - 18f64.sqrt()).abs() < 1e-9);
+    }
+
+    #[test]
+    fn unit_conversions() {
+        assert!((Temperature::kelvin(288.15).as_celsius() - 15.0).abs() < 1e-9);
+        assert!((Temperature::celsius(100.0).as_fahrenheit() - 212.0).abs() < 1e-9);
+        assert!((Pressure::pascal(101_325.0).as_hpa() - 1013.25).abs() < 1e-9);
+        assert!((Pressure::hpa(1013.25).as_inhg() - 29.92).abs() < 0.01);
+        assert!((Pressure::hpa(1013.25).as_mmhg() - 760.0).abs() < 0.01);
+    }
+}
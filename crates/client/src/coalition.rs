@@ -1,5 +1,33 @@
+use crate::{Client, Error};
+
 enum_number!(Coalition {
     Neutral = 0,
     Red = 1,
     Blue = 2,
 });
+
+impl Coalition {
+    /// Displays the given `text` to all players of this coalition. See
+    /// [Client::out_text_for_coalition].
+    pub fn out_text<C>(
+        self,
+        client: &Client<C>,
+        text: &str,
+        display_time: usize,
+        clear_view: bool,
+    ) -> Result<(), Error>
+    where
+        for<'de> C: serde::Serialize + serde::Deserialize<'de>,
+    {
+        client.out_text_for_coalition(self, text, display_time, clear_view)
+    }
+
+    /// Plays the sound `file` to all players of this coalition. See
+    /// [Client::out_sound_for_coalition].
+    pub fn out_sound<C>(self, client: &Client<C>, file: &str) -> Result<(), Error>
+    where
+        for<'de> C: serde::Serialize + serde::Deserialize<'de>,
+    {
+        client.out_sound_for_coalition(self, file)
+    }
+}
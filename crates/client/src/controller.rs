@@ -0,0 +1,222 @@
+use std::ops::{BitOr, BitOrAssign};
+
+use crate::event::{Object, RawTarget};
+use crate::jsonrpc::Client;
+use crate::{Action, Error, Group, OptionParams, Position, Task, Unit, Vector};
+
+/// The group or unit whose AI controller is addressed. Serializes into either
+/// `{"group": {"name": ...}}` or `{"unit": {"name": ...}}`.
+#[derive(Clone, Copy, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub(crate) enum Controlled<'a> {
+    Group(&'a Group),
+    Unit(&'a Unit),
+}
+
+pub(crate) fn set_task(
+    client: &Client,
+    controlled: Controlled<'_>,
+    task: &Task,
+) -> Result<(), Error> {
+    #[derive(Serialize)]
+    struct Params<'a> {
+        #[serde(flatten)]
+        controlled: Controlled<'a>,
+        task: &'a Task,
+    }
+
+    client.notification("controllerSetTask", Some(Params { controlled, task }))
+}
+
+pub(crate) fn push_task(
+    client: &Client,
+    controlled: Controlled<'_>,
+    task: &Task,
+) -> Result<(), Error> {
+    #[derive(Serialize)]
+    struct Params<'a> {
+        #[serde(flatten)]
+        controlled: Controlled<'a>,
+        task: &'a Task,
+    }
+
+    client.notification("controllerPushTask", Some(Params { controlled, task }))
+}
+
+pub(crate) fn pop_task(client: &Client, controlled: Controlled<'_>) -> Result<(), Error> {
+    client.notification("controllerPopTask", Some(controlled))
+}
+
+pub(crate) fn reset_task(client: &Client, controlled: Controlled<'_>) -> Result<(), Error> {
+    client.notification("controllerResetTask", Some(controlled))
+}
+
+/// Returns `None` if the controlled group or unit does not exist anymore.
+pub(crate) fn has_task(client: &Client, controlled: Controlled<'_>) -> Result<Option<bool>, Error> {
+    client.request("controllerHasTask", Some(controlled))
+}
+
+pub(crate) fn set_command(
+    client: &Client,
+    controlled: Controlled<'_>,
+    command: &Action,
+) -> Result<(), Error> {
+    #[derive(Serialize)]
+    struct Params<'a> {
+        #[serde(flatten)]
+        controlled: Controlled<'a>,
+        command: &'a Action,
+    }
+
+    client.notification(
+        "controllerSetCommand",
+        Some(Params {
+            controlled,
+            command,
+        }),
+    )
+}
+
+pub(crate) fn set_option(
+    client: &Client,
+    controlled: Controlled<'_>,
+    option: &OptionParams,
+) -> Result<(), Error> {
+    #[derive(Serialize)]
+    struct Params<'a> {
+        #[serde(flatten)]
+        controlled: Controlled<'a>,
+        #[serde(flatten)]
+        option: &'a OptionParams,
+    }
+
+    client.notification("controllerSetOption", Some(Params { controlled, option }))
+}
+
+/// A set of detection methods (combine them with `|`, e.g. `Detection::RADAR | Detection::RWR`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Detection(u8);
+
+impl Detection {
+    pub const VISUAL: Detection = Detection(1);
+    pub const OPTIC: Detection = Detection(2);
+    pub const RADAR: Detection = Detection(4);
+    pub const IRST: Detection = Detection(8);
+    pub const RWR: Detection = Detection(16);
+    pub const DLINK: Detection = Detection(32);
+
+    pub fn contains(self, other: Detection) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// The individual detection methods of the set.
+    fn methods(self) -> Vec<u8> {
+        (0..6)
+            .map(|i| 1 << i)
+            .filter(|flag| self.0 & flag != 0)
+            .collect()
+    }
+}
+
+impl BitOr for Detection {
+    type Output = Detection;
+
+    fn bitor(self, rhs: Detection) -> Detection {
+        Detection(self.0 | rhs.0)
+    }
+}
+
+impl BitOrAssign for Detection {
+    fn bitor_assign(&mut self, rhs: Detection) {
+        self.0 |= rhs.0;
+    }
+}
+
+/// A target detected by a group's or unit's AI controller.
+#[derive(Debug, Clone)]
+pub struct DetectedTarget {
+    pub object: Object,
+    /// Whether the target is visible (in line of sight).
+    pub visible: bool,
+    /// Whether the target's type is known.
+    pub type_known: bool,
+    /// Whether the distance to the target is known.
+    pub distance_known: bool,
+    /// The methods the target is currently detected with.
+    pub detection: Detection,
+    /// The mission time the target was last seen at.
+    pub last_seen: Option<f64>,
+    pub last_position: Option<Position>,
+    pub last_velocity: Option<Vector>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawDetectedTarget {
+    object: RawTarget,
+    visible: bool,
+    #[serde(rename = "type")]
+    type_known: bool,
+    #[serde(rename = "distance")]
+    distance_known: bool,
+    detection: u8,
+    last_time: Option<f64>,
+    last_pos: Option<Position>,
+    last_vel: Option<Vector>,
+}
+
+/// Returns `None` if the controlled group or unit does not exist anymore.
+pub(crate) fn detected_targets(
+    client: &Client,
+    controlled: Controlled<'_>,
+    detection: Detection,
+) -> Result<Option<Vec<DetectedTarget>>, Error> {
+    #[derive(Serialize)]
+    struct Params<'a> {
+        #[serde(flatten)]
+        controlled: Controlled<'a>,
+        detection: Vec<u8>,
+    }
+
+    let targets: Option<Vec<RawDetectedTarget>> = client.request(
+        "controllerDetectedTargets",
+        Some(Params {
+            controlled,
+            detection: detection.methods(),
+        }),
+    )?;
+
+    Ok(targets.map(|targets| {
+        targets
+            .into_iter()
+            .map(|t| DetectedTarget {
+                object: t.object.into_object(client.clone()),
+                visible: t.visible,
+                type_known: t.type_known,
+                distance_known: t.distance_known,
+                detection: Detection(t.detection),
+                last_seen: t.last_time,
+                last_position: t.last_pos,
+                last_velocity: t.last_vel,
+            })
+            .collect()
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detection_methods() {
+        let detection = Detection::RADAR | Detection::RWR;
+        assert!(detection.contains(Detection::RADAR));
+        assert!(!detection.contains(Detection::VISUAL));
+        assert_eq!(detection.methods(), vec![4, 16]);
+        assert!(Detection::default().methods().is_empty());
+    }
+}
@@ -1,3 +1,5 @@
+use crate::{Client, Error};
+
 enum_number!(Country {
     Russia = 0,
     Ukraine = 1,
@@ -75,3 +77,29 @@ enum_number!(Country {
     UnitedArabEmirates = 74,
     SouthAfrica = 75,
 });
+
+impl Country {
+    /// Displays the given `text` to all players of this country. See
+    /// [Client::out_text_for_country].
+    pub fn out_text<C>(
+        self,
+        client: &Client<C>,
+        text: &str,
+        display_time: usize,
+        clear_view: bool,
+    ) -> Result<(), Error>
+    where
+        for<'de> C: serde::Serialize + serde::Deserialize<'de>,
+    {
+        client.out_text_for_country(self, text, display_time, clear_view)
+    }
+
+    /// Plays the sound `file` to all players of this country. See
+    /// [Client::out_sound_for_country].
+    pub fn out_sound<C>(self, client: &Client<C>, file: &str) -> Result<(), Error>
+    where
+        for<'de> C: serde::Serialize + serde::Deserialize<'de>,
+    {
+        client.out_sound_for_country(self, file)
+    }
+}
@@ -10,11 +10,13 @@ pub enum Error {
     GroupGone(String),
     UnitGone(String),
     StaticGone(String),
+    WeaponGone(String),
     ZoneGone(String),
     NonExistent,
     NoData(String),
-    AddGroupTimeout,
-    AddStaticTimeout,
+    SpawnTimeout(String),
+    InvalidOption(String),
+    InvalidRoute(String),
 }
 
 impl fmt::Display for Error {
@@ -26,7 +28,11 @@ impl fmt::Display for Error {
             GroupGone(ref id) => write!(f, "Group {} does not exist anymore", id)?,
             UnitGone(ref id) => write!(f, "Unit {} does not exist anymore", id)?,
             StaticGone(ref id) => write!(f, "Static {} does not exist anymore", id)?,
+            WeaponGone(ref id) => write!(f, "Weapon {} does not exist anymore", id)?,
             NoData(ref name) => write!(f, "No data for {} found (there will only be data for groups defined in the Mission Editor)", name)?,
+            SpawnTimeout(ref name) => write!(f, "{} was not born within the given timeout", name)?,
+            InvalidOption(ref reason) => write!(f, "Invalid AI option: {}", reason)?,
+            InvalidRoute(ref reason) => write!(f, "Invalid route: {}", reason)?,
             _ => write!(f, "Error: {}", self.description())?,
         }
 
@@ -53,15 +59,13 @@ impl error::Error for Error {
             GroupGone(_) => "Group does not exist anymore",
             UnitGone(_) => "Unit does not exist anymore",
             StaticGone(_) => "Static does not exist anymore",
+            WeaponGone(_) => "Weapon does not exist anymore",
             ZoneGone(_) => "Zone does not exist",
             NonExistent => "Airbase does not exist",
             NoData(_) => "No group data found",
-            AddGroupTimeout => {
-                "A newly added group did not exist 1 second after its supposed spawn"
-            }
-            AddStaticTimeout => {
-                "A newly added statics did not exist 1 second after its supposed spawn"
-            }
+            SpawnTimeout(_) => "A newly added group or static was not born in time",
+            InvalidOption(_) => "AI option is not supported by the group's or unit's category",
+            InvalidRoute(_) => "Route would be ignored by DCS",
         }
     }
 
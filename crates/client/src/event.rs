@@ -1,7 +1,7 @@
 use std::fmt;
 
 use crate::jsonrpc::Client;
-use crate::{Airbase, Coalition, Error, Position, Scenery, Static, Unit, Weapon};
+use crate::{Airbase, Coalition, Error, Group, Position, Scenery, Static, Unit, Weapon};
 use serde_json::Value;
 
 #[derive(Debug, Clone)]
@@ -255,6 +255,86 @@ where
         text: String,
     },
 
+    /// Occurs when a tracked weapon hit the ground, or disappeared within 50 m of its target
+    /// (requires weapon tracking to be enabled, see [crate::Client::track_weapons]).
+    WeaponImpact {
+        /// The event's mission time.
+        time: f64,
+        /// The unit that fired the weapon.
+        initiator: Option<Unit>,
+        /// The weapon, which does not exist anymore.
+        weapon: Weapon,
+        /// The weapon's type name.
+        weapon_type: String,
+        /// The position of the impact.
+        position: Position,
+        /// The object the weapon was guided to.
+        target: Option<Object>,
+        /// The distance between the impact and the weapon's target in m.
+        target_distance: Option<f64>,
+        /// The time in seconds between firing the weapon and its impact.
+        flight_time: f64,
+    },
+
+    /// Occurs when a tracked weapon disappeared without hitting the ground or getting close to its
+    /// target, e.g. because it self-destructed or detonated in the air (requires weapon tracking
+    /// to be enabled, see [crate::Client::track_weapons]).
+    WeaponGone {
+        /// The event's mission time.
+        time: f64,
+        /// The unit that fired the weapon.
+        initiator: Option<Unit>,
+        /// The weapon, which does not exist anymore.
+        weapon: Weapon,
+        /// The weapon's type name.
+        weapon_type: String,
+        /// The last known position of the weapon.
+        position: Position,
+        /// The object the weapon was guided to.
+        target: Option<Object>,
+        /// The distance between the last known position and the weapon's target in m.
+        target_distance: Option<f64>,
+        /// The time in seconds between firing the weapon and its disappearance.
+        flight_time: f64,
+    },
+
+    /// Occurs when a unit enters a watched zone (see [crate::Client::watch_zone]).
+    ZoneEnter {
+        /// The event's mission time.
+        time: f64,
+        /// The ID of the zone watch.
+        watch: u64,
+        /// The name of the zone.
+        zone: String,
+        /// The unit that entered the zone.
+        initiator: Unit,
+    },
+
+    /// Occurs when a unit leaves a watched zone, or when a unit inside the zone does not exist
+    /// anymore (see [crate::Client::watch_zone]).
+    ZoneLeave {
+        /// The event's mission time.
+        time: f64,
+        /// The ID of the zone watch.
+        watch: u64,
+        /// The name of the zone.
+        zone: String,
+        /// The unit that left the zone.
+        initiator: Unit,
+    },
+
+    /// Occurs when the value of a watched user flag changed (see [crate::Client::watch_flags]).
+    FlagChanged {
+        /// The event's mission time.
+        time: f64,
+        /// The name of the flag.
+        flag: String,
+        /// The flag's previous value.
+        old: i64,
+        /// The flag's new value.
+        new: i64,
+    },
+
     /// Occurs when a player selects an F10-menu command.
     CommandSelect {
         /// The event's mission time.
@@ -264,12 +344,14 @@ where
     },
 }
 
+// The values mirror `Object.Category` of the mission environment (see `missionenv.txt`, which is
+// checked by the tests below).
 enum_number!(ObjectCategory {
   Unit    = 1,
   Weapon  = 2,
   Static  = 3,
-  Scenery = 4,
-  Base    = 5,
+  Base    = 4,
+  Scenery = 5,
   Cargo   = 6,
 });
 
@@ -285,6 +367,19 @@ pub(crate) struct RawTarget {
     name: String,
 }
 
+impl RawTarget {
+    pub(crate) fn into_object(self, client: Client) -> Object {
+        match self.category {
+            ObjectCategory::Unit => Object::Unit(Unit::new(client, self.name)),
+            ObjectCategory::Weapon => Object::Weapon(Weapon::new(client, self.id)),
+            ObjectCategory::Static => Object::Static(Static::new(client, self.name)),
+            ObjectCategory::Scenery => Object::Scenery(Scenery::new(client, self.name)),
+            ObjectCategory::Base => Object::Base(Airbase::new(client, self.name)),
+            ObjectCategory::Cargo => Object::Cargo(Static::new(client, self.name)),
+        }
+    }
+}
+
 #[derive(Clone, Deserialize)]
 pub(crate) enum RawEvent {
     Shot {
@@ -430,6 +525,51 @@ pub(crate) enum RawEvent {
         text: String,
     },
 
+    #[serde(rename_all = "camelCase")]
+    WeaponImpact {
+        time: f64,
+        initiator: Option<String>,
+        weapon: ID,
+        weapon_type: String,
+        position: Position,
+        target: Option<RawTarget>,
+        target_distance: Option<f64>,
+        flight_time: f64,
+    },
+
+    #[serde(rename_all = "camelCase")]
+    WeaponGone {
+        time: f64,
+        initiator: Option<String>,
+        weapon: ID,
+        weapon_type: String,
+        position: Position,
+        target: Option<RawTarget>,
+        target_distance: Option<f64>,
+        flight_time: f64,
+    },
+
+    ZoneEnter {
+        time: f64,
+        watch: u64,
+        zone: String,
+        initiator: String,
+    },
+
+    ZoneLeave {
+        time: f64,
+        watch: u64,
+        zone: String,
+        initiator: String,
+    },
+
+    FlagChanged {
+        time: f64,
+        flag: String,
+        old: i64,
+        new: i64,
+    },
+
     CommandSelect {
         time: f64,
         command: Value,
@@ -460,28 +600,7 @@ impl RawEvent {
                 time,
                 initiator: Unit::new(client.clone(), initiator),
                 weapon: weapon.map(|w| Weapon::new(client.clone(), w.id)),
-                target: {
-                    match target.category {
-                        ObjectCategory::Unit => {
-                            Object::Unit(Unit::new(client.clone(), target.name))
-                        }
-                        ObjectCategory::Weapon => {
-                            Object::Weapon(Weapon::new(client.clone(), target.id))
-                        }
-                        ObjectCategory::Static => {
-                            Object::Static(Static::new(client.clone(), target.name))
-                        }
-                        ObjectCategory::Scenery => {
-                            Object::Scenery(Scenery::new(client.clone(), target.name))
-                        }
-                        ObjectCategory::Base => {
-                            Object::Base(Airbase::new(client.clone(), target.name))
-                        }
-                        ObjectCategory::Cargo => {
-                            Object::Cargo(Static::new(client.clone(), target.name))
-                        }
-                    }
-                },
+                target: target.into_object(client),
             },
             RawEvent::Takeoff {
                 time,
@@ -619,6 +738,77 @@ impl RawEvent {
                 pos,
                 text,
             },
+            RawEvent::WeaponImpact {
+                time,
+                initiator,
+                weapon,
+                weapon_type,
+                position,
+                target,
+                target_distance,
+                flight_time,
+            } => Event::WeaponImpact {
+                time,
+                initiator: initiator.map(|id| Unit::new(client.clone(), id)),
+                weapon: Weapon::new(client.clone(), weapon.id),
+                weapon_type,
+                position,
+                target: target.map(|target| target.into_object(client)),
+                target_distance,
+                flight_time,
+            },
+            RawEvent::WeaponGone {
+                time,
+                initiator,
+                weapon,
+                weapon_type,
+                position,
+                target,
+                target_distance,
+                flight_time,
+            } => Event::WeaponGone {
+                time,
+                initiator: initiator.map(|id| Unit::new(client.clone(), id)),
+                weapon: Weapon::new(client.clone(), weapon.id),
+                weapon_type,
+                position,
+                target: target.map(|target| target.into_object(client)),
+                target_distance,
+                flight_time,
+            },
+            RawEvent::ZoneEnter {
+                time,
+                watch,
+                zone,
+                initiator,
+            } => Event::ZoneEnter {
+                time,
+                watch,
+                zone,
+                initiator: Unit::new(client, initiator),
+            },
+            RawEvent::ZoneLeave {
+                time,
+                watch,
+                zone,
+                initiator,
+            } => Event::ZoneLeave {
+                time,
+                watch,
+                zone,
+                initiator: Unit::new(client, initiator),
+            },
+            RawEvent::FlagChanged {
+                time,
+                flag,
+                old,
+                new,
+            } => Event::FlagChanged {
+                time,
+                flag,
+                old,
+                new,
+            },
             RawEvent::CommandSelect { time, command } => Event::CommandSelect {
                 time,
                 command: serde_json::from_value(command)?,
@@ -628,6 +818,25 @@ impl RawEvent {
     }
 }
 
+impl<C> Event<C>
+where
+    for<'de> C: serde::Serialize + serde::Deserialize<'de>,
+{
+    /// The group the visibility of a [Event::MarkAdd], [Event::MarkChange] or
+    /// [Event::MarkRemove] event's mark is restricted for. Returns `None` for any other event and
+    /// for marks that are not restricted to a group.
+    pub fn mark_group(&self, client: &crate::Client<C>) -> Result<Option<Group>, Error> {
+        match self {
+            Event::MarkAdd { group_id, .. }
+            | Event::MarkChange { group_id, .. }
+            | Event::MarkRemove { group_id, .. } => {
+                group_id.map(|id| client.group_by_id(id)).transpose()
+            }
+            _ => Ok(None),
+        }
+    }
+}
+
 impl<C> fmt::Display for Event<C>
 where
     for<'de> C: serde::Serialize + serde::Deserialize<'de> + fmt::Display,
@@ -735,6 +944,40 @@ where
             MarkRemove {
                 time, text, pos, ..
             } => write!(f, "[{}] A mark has been removed at {}: {}", time, pos, text),
+            WeaponImpact {
+                time,
+                weapon_type,
+                position,
+                ..
+            } => write!(f, "[{}] {} impacted at {}", time, weapon_type, position),
+            WeaponGone {
+                time,
+                weapon_type,
+                position,
+                ..
+            } => write!(f, "[{}] {} disappeared at {}", time, weapon_type, position),
+            ZoneEnter {
+                time,
+                zone,
+                initiator,
+                ..
+            } => write!(f, "[{}] {} entered zone {}", time, initiator, zone),
+            ZoneLeave {
+                time,
+                zone,
+                initiator,
+                ..
+            } => write!(f, "[{}] {} left zone {}", time, initiator, zone),
+            FlagChanged {
+                time,
+                flag,
+                old,
+                new,
+            } => write!(
+                f,
+                "[{}] Flag {} changed from {} to {}",
+                time, flag, old, new
+            ),
             CommandSelect { time, command } => {
                 write!(f, "[{}] Menu command {} selected", time, command)
             }
@@ -756,3 +999,44 @@ impl fmt::Display for Object {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    /// Parses the `Object.Category` table of `missionenv.txt` into a map of `name => value`.
+    fn mission_env_object_categories() -> HashMap<String, u64> {
+        let env = include_str!("../../../missionenv.txt");
+        let start = env.find("parentClass_ = <3>{").unwrap();
+        let start = start + env[start..].find("Category = {").unwrap();
+        let end = start + env[start..].find('}').unwrap();
+
+        env[start..end]
+            .lines()
+            .skip(1)
+            .filter_map(|line| {
+                let line = line.trim().trim_end_matches(',');
+                let pos = line.find(" = ")?;
+                Some((line[..pos].to_string(), line[pos + 3..].parse().unwrap()))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn object_categories_match_mission_env() {
+        let env = mission_env_object_categories();
+        let categories = vec![
+            ("UNIT", ObjectCategory::Unit),
+            ("WEAPON", ObjectCategory::Weapon),
+            ("STATIC", ObjectCategory::Static),
+            ("BASE", ObjectCategory::Base),
+            ("SCENERY", ObjectCategory::Scenery),
+            ("CARGO", ObjectCategory::Cargo),
+        ];
+
+        for (name, category) in categories {
+            assert_eq!(env[name], category as u64, "Object.Category.{}", name);
+        }
+    }
+}
@@ -0,0 +1,57 @@
+use std::collections::HashSet;
+use std::sync::mpsc::Receiver;
+
+use crate::event::RawEvent;
+
+/// A change of a watched user flag (see [crate::Client::watch_flags]).
+#[derive(Debug, Clone, PartialEq)]
+pub struct FlagChange {
+    /// The mission time of the change.
+    pub time: f64,
+    pub flag: String,
+    pub old: i64,
+    pub new: i64,
+}
+
+/// An endless iterator that yields all future changes of the watched user flags (see
+/// [crate::Client::watch_flags]).
+pub struct FlagWatch {
+    flags: HashSet<String>,
+    rx: Receiver<RawEvent>,
+}
+
+impl FlagWatch {
+    pub(crate) fn new(flags: HashSet<String>, rx: Receiver<RawEvent>) -> Self {
+        FlagWatch { flags, rx }
+    }
+
+    /// The names of the watched flags.
+    pub fn flags(&self) -> impl Iterator<Item = &str> {
+        self.flags.iter().map(String::as_str)
+    }
+}
+
+impl Iterator for FlagWatch {
+    type Item = FlagChange;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.rx.recv().ok()? {
+                RawEvent::FlagChanged {
+                    time,
+                    flag,
+                    old,
+                    new,
+                } if self.flags.contains(&flag) => {
+                    return Some(FlagChange {
+                        time,
+                        flag,
+                        old,
+                        new,
+                    })
+                }
+                _ => {}
+            }
+        }
+    }
+}
@@ -1,9 +1,13 @@
 use std::fmt;
 use std::hash::{Hash, Hasher};
 
+use crate::controller::{self, Controlled};
 use crate::jsonrpc::Client;
-use crate::unit::UnitIterator;
-use crate::{Coalition, Country, Error};
+use crate::unit::{Unit, UnitIterator};
+use crate::{
+    AiOption, Callsign, Coalition, Country, DetectedTarget, Detection, Error, Payload, Position,
+    RouteBuilder, Speed,
+};
 use serde_json::Value;
 use std::cell::RefCell;
 
@@ -145,6 +149,90 @@ impl Group {
         )
     }
 
+    /// Sets the task of the group's AI controller, overwriting all existing tasks.
+    pub fn set_task(&self, task: &Task) -> Result<(), Error> {
+        controller::set_task(&self.client, Controlled::Group(self), task)
+    }
+
+    /// Pushes the given `task` to the front of the group's task queue. The previous task is
+    /// resumed once the pushed task is finished.
+    pub fn push_task(&self, task: &Task) -> Result<(), Error> {
+        controller::push_task(&self.client, Controlled::Group(self), task)
+    }
+
+    /// Removes the task at the front of the group's task queue.
+    pub fn pop_task(&self) -> Result<(), Error> {
+        controller::pop_task(&self.client, Controlled::Group(self))
+    }
+
+    /// Removes all tasks of the group.
+    pub fn reset_task(&self) -> Result<(), Error> {
+        controller::reset_task(&self.client, Controlled::Group(self))
+    }
+
+    /// Returns whether the group currently has a task.
+    pub fn has_task(&self) -> Result<bool, Error> {
+        controller::has_task(&self.client, Controlled::Group(self))?
+            .ok_or_else(|| Error::GroupGone(self.name.clone()))
+    }
+
+    /// Executes the given `command` immediately (commands are not queued like tasks).
+    pub fn set_command(&self, command: &Action) -> Result<(), Error> {
+        controller::set_command(&self.client, Controlled::Group(self), command)
+    }
+
+    /// Returns the targets detected by the group's AI controller with any of the given `detection`
+    /// methods (or with any method if `detection` is empty).
+    pub fn detected_targets(&self, detection: Detection) -> Result<Vec<DetectedTarget>, Error> {
+        controller::detected_targets(&self.client, Controlled::Group(self), detection)?
+            .ok_or_else(|| Error::GroupGone(self.name.clone()))
+    }
+
+    /// Sets an option of the group's AI controller, e.g.
+    /// `group.set_option(AirOption::Roe(Roe::WeaponHold))`. Returns an
+    /// [Error::InvalidOption] if the option is not supported by the group's category.
+    pub fn set_option<O: Into<AiOption>>(&self, option: O) -> Result<(), Error> {
+        let option = option.into();
+        option.validate_for_group(self.category()?)?;
+        controller::set_option(&self.client, Controlled::Group(self), &option.to_params())
+    }
+
+    /// Plays the sound `file` to all players of this group.
+    pub fn out_sound(&self, file: &str) -> Result<(), Error> {
+        #[derive(Serialize)]
+        struct Params<'a> {
+            group: &'a Group,
+            file: &'a str,
+        }
+
+        self.client
+            .notification("outSoundForGroup", Some(Params { group: self, file }))
+    }
+
+    /// Orders the (ground) group to move to the given position at the given `speed`, either on
+    /// roads or cross-country. Replaces the group's current route.
+    pub fn move_to(&self, position: &Position, speed: Speed, on_road: bool) -> Result<(), Error> {
+        // units are yielded in reverse order, so the last one is the group's leader
+        let start = self
+            .units()?
+            .last()
+            .ok_or_else(|| Error::GroupGone(self.name.clone()))?
+            .position()?;
+        let action = if on_road {
+            WaypointAction::OnRoad
+        } else {
+            WaypointAction::OffRoad
+        };
+        let route = RouteBuilder::new()
+            .ground_point(start.x, start.y, speed, action.clone())
+            .ground_point(position.x, position.y, speed, action)
+            .build()?;
+
+        self.set_task(&Task::Mission {
+            params: MissionParams { route },
+        })
+    }
+
     pub fn destroy(self) -> Result<(), Error> {
         self.client.notification("groupDestory", Some(&self))
     }
@@ -217,7 +305,7 @@ pub struct RouteData {
     pub route_relative_tot: bool,
 }
 
-// known unimplemented properties: airdromeId, helipadId, formation_template
+// known unimplemented properties: formation_template
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PointData {
     #[serde(rename = "ETA")]
@@ -236,6 +324,12 @@ pub struct PointData {
     pub kind: WaypointType,
     pub x: f64,
     pub y: f64,
+    /// The airdrome of takeoff and landing waypoints.
+    #[serde(rename = "airdromeId", skip_serializing_if = "Option::is_none")]
+    pub airdrome_id: Option<u64>,
+    /// The FARP or ship of takeoff and landing waypoints.
+    #[serde(rename = "helipadId", skip_serializing_if = "Option::is_none")]
+    pub helipad_id: Option<u64>,
     // TODO: linkUnit for statics
 }
 
@@ -329,6 +423,15 @@ pub enum Task {
         auto: bool,
         params: EmbarkToTransportParams,
     },
+    /// Follows the given route (replaces the group's current route).
+    Mission {
+        params: MissionParams,
+    },
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MissionParams {
+    pub route: RouteData,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -390,7 +493,27 @@ pub struct AttackGroupParams {
     #[serde(rename = "weaponType")]
     pub weapon_type: usize, // TODO: flags?
     #[serde(rename = "groupId")]
-    pub group_id: usize, // TODO: directly provide Group type
+    pub group_id: usize,
+}
+
+impl EngageGroupParams {
+    /// Returns the group to engage.
+    pub fn group<C>(&self, client: &crate::Client<C>) -> Result<Group, Error>
+    where
+        for<'de> C: serde::Serialize + serde::Deserialize<'de>,
+    {
+        client.group_by_id(self.group_id as u64)
+    }
+}
+
+impl AttackGroupParams {
+    /// Returns the group to attack.
+    pub fn group<C>(&self, client: &crate::Client<C>) -> Result<Group, Error>
+    where
+        for<'de> C: serde::Serialize + serde::Deserialize<'de>,
+    {
+        client.group_by_id(self.group_id as u64)
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -408,6 +531,16 @@ pub struct AttackUnitParams {
     pub direction: usize,   // TODO
 }
 
+impl AttackUnitParams {
+    /// Returns the unit to attack.
+    pub fn unit<C>(&self, client: &crate::Client<C>) -> Result<Unit, Error>
+    where
+        for<'de> C: serde::Serialize + serde::Deserialize<'de>,
+    {
+        client.unit_by_id(self.unit_id as u64)
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct WrappedActionParams {
     pub action: Action,
@@ -446,7 +579,7 @@ pub struct OptionParams {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SetInvisibleParams {
-    value: bool,
+    pub value: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -481,20 +614,20 @@ pub struct SetFrequencyParams {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct OrbitParams {
-    altitude: f64,
+    pub altitude: f64,
     #[serde(default)]
-    altitude_edited: bool,
-    pattern: OrbitKind,
-    speed: f64,
+    pub altitude_edited: bool,
+    pub pattern: OrbitKind,
+    pub speed: f64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct LandParams {
-    duration: u64,
-    duration_flag: bool,
-    x: f64,
-    y: f64,
+    pub duration: u64,
+    pub duration_flag: bool,
+    pub x: f64,
+    pub y: f64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -536,11 +669,11 @@ pub struct UnitData {
     #[serde(default)]
     pub alt_type: AltitudeType,
     // statics do not have a callsign
-    #[serde(rename = "callsignCompat", skip_serializing_if = "Option::is_none")]
-    pub callsign: Option<Vec<u16>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub callsign: Option<Callsign>,
     #[serde(default)]
     pub heading: f64,
-    pub payload: Option<Value>, // TODO
+    pub payload: Option<Payload>,
     pub skill: Option<Skill>,
     #[serde(default)]
     pub speed: f64,
@@ -619,6 +752,10 @@ pub enum WaypointAction {
     FlyOverPoint,
     #[serde(rename = "Off Road")]
     OffRoad,
+    #[serde(rename = "On Road")]
+    OnRoad,
+    #[serde(rename = "On Railroads")]
+    OnRailroads,
 }
 
 impl Default for WaypointAction {
@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::io::prelude::*;
 use std::io::BufReader;
 use std::net::{TcpStream, ToSocketAddrs};
@@ -8,6 +8,7 @@ use std::time::{Duration, Instant};
 
 use crate::error::Error;
 use crate::event::RawEvent;
+use crate::UnitDesc;
 use dcsjsonrpc_common::{Notification, Request, Response, Version, ID};
 use serde_json::Value;
 
@@ -20,6 +21,12 @@ pub struct Client {
     pending: Arc<Mutex<HashMap<ID, Pending>>>,
     next_id: Arc<Mutex<i64>>,
     subscriptions: Arc<Mutex<Vec<mpsc::Sender<RawEvent>>>>,
+    births: Arc<Mutex<Vec<BirthWatch>>>,
+    next_birth_watch_id: Arc<Mutex<u64>>,
+    // the server sends events once per subscription, so only subscribe once per connection
+    subscribed: Arc<Mutex<bool>>,
+    // unit type descriptions are the same for all units of a type, so they are cached per type
+    pub(crate) type_descs: Arc<Mutex<HashMap<String, UnitDesc>>>,
 }
 
 struct Pending {
@@ -27,6 +34,22 @@ struct Pending {
     created_at: Instant,
 }
 
+/// Forwards the birth events of the objects that have not been born yet, and is removed once all
+/// of them are born.
+struct BirthWatch {
+    id: u64,
+    unborn: HashSet<String>,
+    tx: mpsc::Sender<String>,
+}
+
+/// Receives the births of a [BirthWatch] (see [Client::watch_births]) and removes the watch
+/// once dropped.
+pub(crate) struct Births {
+    id: u64,
+    watches: Arc<Mutex<Vec<BirthWatch>>>,
+    rx: mpsc::Receiver<String>,
+}
+
 #[derive(Debug, Deserialize)]
 #[serde(untagged)]
 enum Incoming {
@@ -43,12 +66,17 @@ impl Client {
 
         let pending = Arc::new(Mutex::new(HashMap::new()));
         let subs = Arc::new(Mutex::new(Vec::new()));
+        let births = Arc::new(Mutex::new(Vec::new()));
         let (tx, rx) = mpsc::channel::<Vec<u8>>();
         let client = Client {
             tx,
             pending: pending.clone(),
             next_id: Arc::new(Mutex::new(0)),
             subscriptions: subs.clone(),
+            births: births.clone(),
+            next_birth_watch_id: Arc::new(Mutex::new(0)),
+            subscribed: Arc::new(Mutex::new(false)),
+            type_descs: Arc::new(Mutex::new(HashMap::new())),
         };
 
         let pending2 = pending.clone();
@@ -102,6 +130,18 @@ impl Client {
                                 }
                             };
 
+                            if let RawEvent::Birth { ref initiator, .. } = event {
+                                let mut births = births.lock().unwrap();
+                                births.retain_mut(|watch| {
+                                    if watch.unborn.remove(initiator)
+                                        && watch.tx.send(initiator.clone()).is_err()
+                                    {
+                                        return false;
+                                    }
+                                    !watch.unborn.is_empty()
+                                });
+                            }
+
                             let mut subs = subs.lock().unwrap();
                             subs.retain(|tx| tx.send(event.clone()).is_ok());
                         }
@@ -209,8 +249,56 @@ impl Client {
         ID::Number(*next_id)
     }
 
-    pub(crate) fn add_subscription(&self, tx: mpsc::Sender<RawEvent>) {
-        let mut subs = self.subscriptions.lock().unwrap();
-        subs.push(tx);
+    /// Forwards all future mission events to `tx`.
+    pub(crate) fn subscribe(&self, tx: mpsc::Sender<RawEvent>) -> Result<(), Error> {
+        self.subscriptions.lock().unwrap().push(tx);
+        self.ensure_subscribed()
+    }
+
+    /// Returns a receiver that yields the name of each of the given objects once it is born. The
+    /// receiver is disconnected once all of them are born, and stops watching once dropped.
+    pub(crate) fn watch_births(&self, unborn: HashSet<String>) -> Result<Births, Error> {
+        let id = {
+            let mut next_id = self.next_birth_watch_id.lock().unwrap();
+            *next_id = next_id.wrapping_add(1);
+            *next_id
+        };
+        let (tx, rx) = mpsc::channel();
+        if !unborn.is_empty() {
+            self.births
+                .lock()
+                .unwrap()
+                .push(BirthWatch { id, unborn, tx });
+        }
+        let births = Births {
+            id,
+            watches: self.births.clone(),
+            rx,
+        };
+        self.ensure_subscribed()?;
+        Ok(births)
+    }
+
+    fn ensure_subscribed(&self) -> Result<(), Error> {
+        let mut subscribed = self.subscribed.lock().unwrap();
+        if !*subscribed {
+            self.request::<(), String>("subscribe", None)?;
+            *subscribed = true;
+        }
+
+        Ok(())
+    }
+}
+
+impl Births {
+    pub(crate) fn recv_timeout(&self, timeout: Duration) -> Result<String, mpsc::RecvTimeoutError> {
+        self.rx.recv_timeout(timeout)
+    }
+}
+
+impl Drop for Births {
+    fn drop(&mut self) {
+        let id = self.id;
+        self.watches.lock().unwrap().retain(|watch| watch.id != id);
     }
 }
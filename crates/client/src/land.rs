@@ -0,0 +1,164 @@
+use crate::jsonrpc::Client;
+use crate::route::point;
+use crate::{Altitude, Error, PointData, Position, Speed, Vector, WaypointAction, WaypointType};
+
+/// Queries the terrain of the map (see [crate::Client::land]).
+///
+/// Map coordinates are given the same way as for [Position], i.e. `x` points north and `y`
+/// points east.
+#[derive(Clone)]
+pub struct Land {
+    client: Client,
+}
+
+enum_number!(SurfaceType {
+    Land = 1,
+    ShallowWater = 2,
+    Water = 3,
+    Road = 4,
+    Runway = 5,
+});
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PathKind {
+    Roads,
+    Rails,
+}
+
+/// A path along roads or railroads (see [Land::find_path]).
+#[derive(Debug, Clone, PartialEq)]
+pub struct Path {
+    pub kind: PathKind,
+    /// The path's points (at terrain height), starting at the point closest to the start.
+    pub points: Vec<Position>,
+}
+
+impl Path {
+    /// Converts the path into waypoints for ground units that follow the road or railroad at the
+    /// given `speed` (see also [crate::RouteBuilder::path]).
+    pub fn waypoints(&self, speed: Speed) -> Vec<PointData> {
+        let action = match self.kind {
+            PathKind::Roads => WaypointAction::OnRoad,
+            PathKind::Rails => WaypointAction::OnRailroads,
+        };
+        self.points
+            .iter()
+            .map(|p| {
+                point(
+                    WaypointType::TurningPoint,
+                    action.clone(),
+                    p.x,
+                    p.y,
+                    Altitude::meters_agl(0.0),
+                    speed.as_mps(),
+                )
+            })
+            .collect()
+    }
+}
+
+#[derive(Serialize)]
+struct Point {
+    x: f64,
+    y: f64,
+}
+
+impl Land {
+    pub(crate) fn new(client: Client) -> Self {
+        Land { client }
+    }
+
+    /// The height of the terrain above sea level at the given map coordinates.
+    pub fn height(&self, x: f64, y: f64) -> Result<f64, Error> {
+        self.client.request("landHeight", Some(Point { x, y }))
+    }
+
+    pub fn surface_type(&self, x: f64, y: f64) -> Result<SurfaceType, Error> {
+        self.client.request("landSurfaceType", Some(Point { x, y }))
+    }
+
+    /// Whether the line of sight between both positions is not blocked by the terrain.
+    pub fn is_visible(&self, from: &Position, to: &Position) -> Result<bool, Error> {
+        #[derive(Serialize)]
+        struct Params<'a> {
+            from: &'a Position,
+            to: &'a Position,
+        }
+
+        self.client
+            .request("landIsVisible", Some(Params { from, to }))
+    }
+
+    /// The position at which a ray starting at `origin` hits the terrain, or `None` if it does
+    /// not hit the terrain within `distance` meters. The `direction` is given in the same
+    /// coordinate system as [crate::Unit::velocity].
+    pub fn ray_intersection(
+        &self,
+        origin: &Position,
+        direction: &Vector,
+        distance: f64,
+    ) -> Result<Option<Position>, Error> {
+        #[derive(Serialize)]
+        struct Params<'a> {
+            origin: &'a Position,
+            direction: &'a Vector,
+            distance: f64,
+        }
+
+        self.client.request(
+            "landIntersection",
+            Some(Params {
+                origin,
+                direction,
+                distance,
+            }),
+        )
+    }
+
+    /// The terrain between both positions, sampled at `samples` evenly spaced points (including
+    /// both ends).
+    pub fn profile(
+        &self,
+        from: &Position,
+        to: &Position,
+        samples: usize,
+    ) -> Result<Vec<Position>, Error> {
+        #[derive(Serialize)]
+        struct Params<'a> {
+            from: &'a Position,
+            to: &'a Position,
+            samples: usize,
+        }
+
+        self.client
+            .request("landProfile", Some(Params { from, to, samples }))
+    }
+
+    /// Finds a path along roads or railroads between both positions. Returns `None` if there is
+    /// no such path.
+    pub fn find_path(
+        &self,
+        from: &Position,
+        to: &Position,
+        kind: PathKind,
+    ) -> Result<Option<Path>, Error> {
+        #[derive(Serialize)]
+        struct Params<'a> {
+            from: &'a Position,
+            to: &'a Position,
+            kind: PathKind,
+        }
+
+        let points: Option<Vec<Position>> = self
+            .client
+            .request("landFindPath", Some(Params { from, to, kind }))?;
+        Ok(points.map(|points| Path { kind, points }))
+    }
+
+    /// The closest point on a road to the given map coordinates (at terrain height).
+    pub fn closest_point_on_roads(&self, x: f64, y: f64) -> Result<Position, Error> {
+        self.client
+            .request("landClosestPointOnRoads", Some(Point { x, y }))
+    }
+}
@@ -8,39 +8,69 @@ extern crate serde_derive;
 #[macro_use]
 mod macros;
 mod airbase;
+mod atmosphere;
 mod coalition;
+mod controller;
 mod country;
 mod error;
 mod event;
+mod flag;
 mod group;
 mod jsonrpc;
+mod land;
 mod menu;
+mod mission;
+mod option;
+mod payload;
 mod position;
+mod route;
 mod scenery;
+mod schedule;
+mod spawn;
 mod staticobject;
+mod template;
+mod time;
 mod unit;
+mod volume;
+mod warehouse;
 mod weapon;
+mod zone;
 
+use std::collections::HashSet;
+use std::fmt;
 use std::net::ToSocketAddrs;
 use std::sync::mpsc::{channel, Receiver};
-use std::thread;
-use std::time::{Duration, Instant};
 
-pub use self::airbase::Airbase;
+pub use self::airbase::*;
+pub use self::atmosphere::*;
 pub use self::coalition::Coalition;
+pub use self::controller::{DetectedTarget, Detection};
 pub use self::country::Country;
 pub use self::error::Error;
-pub use self::event::Event;
-use self::event::RawEvent;
+pub use self::event::{Event, Object, ObjectCategory};
+use self::event::{RawEvent, RawTarget};
+pub use self::flag::{FlagChange, FlagWatch};
 pub use self::group::*;
+pub use self::land::{Land, Path, PathKind, SurfaceType};
 pub use self::menu::*;
+pub use self::mission::*;
+pub use self::option::*;
+pub use self::payload::*;
 pub use self::position::Position;
+pub use self::route::*;
 pub use self::scenery::Scenery;
+pub use self::schedule::{Schedule, ScheduledAction};
+pub use self::spawn::Spawn;
 pub use self::staticobject::*;
+pub use self::template::*;
+pub use self::time::{Date, DateTime, MissionTime};
 pub use self::unit::*;
-pub use self::weapon::Weapon;
+pub use self::volume::Volume;
+pub use self::warehouse::*;
+pub use self::weapon::*;
+use self::zone::RawZone;
+pub use self::zone::{Color, Zone, ZoneFilter, ZoneShape};
 pub use dcsjsonrpc_common::*;
-use std::cell::RefCell;
 
 pub struct Client<C = usize>
 where
@@ -83,6 +113,134 @@ where
         )
     }
 
+    /// Displays the given `text` to all players of the given `coalition` for `display_time`
+    /// seconds. See [Client::out_text] for `clear_view`.
+    pub fn out_text_for_coalition(
+        &self,
+        coalition: Coalition,
+        text: &str,
+        display_time: usize,
+        clear_view: bool,
+    ) -> Result<(), Error> {
+        #[derive(Serialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Params<'a> {
+            coalition: Coalition,
+            text: &'a str,
+            display_time: usize,
+            clear_view: bool,
+        }
+
+        self.client.notification(
+            "outTextForCoalition",
+            Some(Params {
+                coalition,
+                text,
+                display_time,
+                clear_view,
+            }),
+        )
+    }
+
+    /// Displays the given `text` to all players of the given `country` for `display_time`
+    /// seconds. See [Client::out_text] for `clear_view`.
+    pub fn out_text_for_country(
+        &self,
+        country: Country,
+        text: &str,
+        display_time: usize,
+        clear_view: bool,
+    ) -> Result<(), Error> {
+        #[derive(Serialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Params<'a> {
+            country: Country,
+            text: &'a str,
+            display_time: usize,
+            clear_view: bool,
+        }
+
+        self.client.notification(
+            "outTextForCountry",
+            Some(Params {
+                country,
+                text,
+                display_time,
+                clear_view,
+            }),
+        )
+    }
+
+    /// Displays the given `text` to the player of the given `unit` for `display_time` seconds.
+    /// See [Client::out_text] for `clear_view`.
+    pub fn out_text_for_unit(
+        &self,
+        unit: &Unit,
+        text: &str,
+        display_time: usize,
+        clear_view: bool,
+    ) -> Result<(), Error> {
+        unit.out_text(text, display_time, clear_view)
+    }
+
+    /// Plays the sound `file` to all players. The `file` must be part of the mission (e.g.
+    /// `l10n/DEFAULT/alarm.ogg`).
+    pub fn out_sound(&self, file: &str) -> Result<(), Error> {
+        #[derive(Serialize)]
+        struct Params<'a> {
+            file: &'a str,
+        }
+
+        self.client.notification("outSound", Some(Params { file }))
+    }
+
+    /// Plays the sound `file` to all players of the given `coalition`.
+    pub fn out_sound_for_coalition(&self, coalition: Coalition, file: &str) -> Result<(), Error> {
+        #[derive(Serialize)]
+        struct Params<'a> {
+            coalition: Coalition,
+            file: &'a str,
+        }
+
+        self.client
+            .notification("outSoundForCoalition", Some(Params { coalition, file }))
+    }
+
+    /// Plays the sound `file` to all players of the given `country`.
+    pub fn out_sound_for_country(&self, country: Country, file: &str) -> Result<(), Error> {
+        #[derive(Serialize)]
+        struct Params<'a> {
+            country: Country,
+            file: &'a str,
+        }
+
+        self.client
+            .notification("outSoundForCountry", Some(Params { country, file }))
+    }
+
+    /// Plays the sound `file` to the player of the given `unit`.
+    pub fn out_sound_for_unit(&self, unit: &Unit, file: &str) -> Result<(), Error> {
+        unit.out_sound(file)
+    }
+
+    /// Transmits the transmission's sound file from its position on its frequency and
+    /// modulation. Everyone tuned into the frequency is able to hear it.
+    pub fn radio_transmission(&self, transmission: &RadioTransmission) -> Result<(), Error> {
+        self.client
+            .notification("radioTransmission", Some(transmission))
+    }
+
+    /// Stops the radio transmission with the given `name` (see [RadioTransmission::name]).
+    pub fn stop_radio_transmission(&self, name: &str) -> Result<(), Error> {
+        #[derive(Serialize)]
+        struct Params<'a> {
+            name: &'a str,
+        }
+
+        self.client
+            .notification("stopRadioTransmission", Some(Params { name }))
+    }
+
     /// Removes the marker identified with `id` from the F10 map.
     pub fn remove_mark(&self, id: usize) -> Result<(), Error> {
         #[derive(Serialize)]
@@ -93,14 +251,14 @@ where
         self.client.notification("removeMark", Some(Params { id }))
     }
 
-    /// Instantiates the group that is identified with the given `name` (group names are unique).
-    /// The group must exist, otherwise an [Error::GroupGone] error is returned.
-    pub fn group(&self, name: &str) -> Result<Group, Error> {
-        let group = Group::new(self.client.clone(), name);
-        if group.exists()? {
-            Ok(group)
-        } else {
-            Err(Error::GroupGone(name.to_string()))
+    /// Instantiates the group that is identified with the given name (group names are unique) or
+    /// runtime ID. The group must exist, otherwise an [Error::GroupGone] error is returned.
+    pub fn group<I: Into<Identifier>>(&self, identifier: I) -> Result<Group, Error> {
+        let identifier = identifier.into();
+        let name: Option<String> = self.client.request("groupName", Some(&identifier))?;
+        match name.map(|name| Group::new(self.client.clone(), name)) {
+            Some(group) if group.exists()? => Ok(group),
+            _ => Err(Error::GroupGone(identifier.to_string())),
         }
     }
 
@@ -144,22 +302,85 @@ where
         }
     }
 
-    pub fn static_object(&self, name: &str) -> Result<Static, Error> {
-        let staticobj = Static::new(self.client.clone(), name);
-        if staticobj.exists()? {
-            Ok(staticobj)
-        } else {
-            Err(Error::StaticGone(name.to_string()))
+    /// Returns all airbases (airdromes, helipads and ships).
+    pub fn airbases(&self) -> Result<Vec<Airbase>, Error> {
+        self.get_airbases(None)
+    }
+
+    /// Returns all airbases (airdromes, helipads and ships) owned by the given `coalition`.
+    pub fn coalition_airbases(&self, coalition: Coalition) -> Result<Vec<Airbase>, Error> {
+        self.get_airbases(Some(coalition))
+    }
+
+    fn get_airbases(&self, coalition: Option<Coalition>) -> Result<Vec<Airbase>, Error> {
+        #[derive(Serialize)]
+        struct Params {
+            #[serde(skip_serializing_if = "Option::is_none")]
+            coalition: Option<Coalition>,
+        }
+
+        let names: Vec<String> = self
+            .client
+            .request("getAirbases", Some(Params { coalition }))?;
+        Ok(names
+            .into_iter()
+            .map(|name| Airbase::new(self.client.clone(), name))
+            .collect())
+    }
+
+    /// Instantiates the unit that is identified with the given name or runtime ID. The unit must
+    /// exist, otherwise an [Error::UnitGone] error is returned.
+    pub fn unit<I: Into<Identifier>>(&self, identifier: I) -> Result<Unit, Error> {
+        let identifier = identifier.into();
+        let name: Option<String> = self.client.request("unitName", Some(&identifier))?;
+        match name.map(|name| Unit::new(self.client.clone(), name)) {
+            Some(unit) if unit.exists()? => Ok(unit),
+            _ => Err(Error::UnitGone(identifier.to_string())),
+        }
+    }
+
+    /// Instantiates the static object that is identified with the given name or runtime ID. The
+    /// static object must exist, otherwise an [Error::StaticGone] error is returned.
+    pub fn static_object<I: Into<Identifier>>(&self, identifier: I) -> Result<Static, Error> {
+        let identifier = identifier.into();
+        let name: Option<String> = self.client.request("staticName", Some(&identifier))?;
+        match name.map(|name| Static::new(self.client.clone(), name)) {
+            Some(staticobj) if staticobj.exists()? => Ok(staticobj),
+            _ => Err(Error::StaticGone(identifier.to_string())),
         }
     }
 
-    /// Adds a new group to the mission.
+    /// Describes the given unit type (e.g. `F-16C_50`). Descriptions are cached per unit type.
+    pub fn type_desc(&self, type_name: &str) -> Result<UnitDesc, Error> {
+        crate::unit::type_desc(&self.client, type_name)
+    }
+
+    /// Returns the group with the given runtime `id` (as used e.g. in task params and mark
+    /// events), or an [Error::GroupGone] error if no such group exists.
+    pub fn group_by_id(&self, id: u64) -> Result<Group, Error> {
+        self.group(id)
+    }
+
+    /// Returns the unit with the given runtime `id`, or an [Error::UnitGone] error if no such
+    /// unit exists.
+    pub fn unit_by_id(&self, id: u64) -> Result<Unit, Error> {
+        self.unit(id)
+    }
+
+    /// Returns the static object with the given runtime `id`, or an [Error::StaticGone] error if
+    /// no such static object exists.
+    pub fn static_by_id(&self, id: u64) -> Result<Static, Error> {
+        self.static_object(id)
+    }
+
+    /// Adds a new group to the mission. Use [Spawn::wait] to wait for the group's units to be
+    /// born.
     pub fn add_group(
         &self,
         country: Country,
         category: GroupCategory,
         data: &GroupData,
-    ) -> Result<Group, Error> {
+    ) -> Result<Spawn<Group>, Error> {
         #[derive(Serialize)]
         struct Params<'a> {
             country: Country,
@@ -167,11 +388,17 @@ where
             data: &'a GroupData,
         }
 
-        let name = match data {
-            GroupData::Aircraft(AircraftGroupData { ref name, .. })
-            | GroupData::Ground(GroundGroupData { ref name, .. }) => name.clone(),
+        let units = match data {
+            GroupData::Aircraft(AircraftGroupData { ref units, .. })
+            | GroupData::Ground(GroundGroupData { ref units, .. }) => units,
         };
-        self.client.notification(
+        let unborn = units.iter().map(|u| u.name.clone()).collect::<HashSet<_>>();
+
+        // watch for births before adding the group to not miss any of them (the watch is removed
+        // again once `births` is dropped, e.g. if adding the group fails)
+        let births = self.client.watch_births(unborn.clone())?;
+
+        let added: Added = self.client.request(
             "addGroup",
             Some(Params {
                 country,
@@ -180,51 +407,103 @@ where
             }),
         )?;
 
-        let mut group = Group::new(self.client.clone(), name);
-        group.country = RefCell::new(Some(country));
-        group.category = RefCell::new(Some(category));
-        group.data = RefCell::new(Some(data.clone()));
-        let started = Instant::now();
-        while !group.exists()? {
-            if started.elapsed() > Duration::from_secs(1) {
-                return Err(Error::AddGroupTimeout);
-            }
-            thread::sleep(Duration::from_millis(100));
-        }
+        let group = Group::new(self.client.clone(), added.name.as_str());
+        group.country.replace(Some(country));
+        group.category.replace(Some(category));
+        group.data.replace(Some(data.clone()));
 
-        Ok(group)
+        Ok(Spawn::new(group, added.name, added.id, unborn, births))
     }
 
-    /// Adds a new static object to the mission.
-    pub fn add_static(&self, country: Country, data: &StaticData) -> Result<Static, Error> {
+    /// Adds a new static object to the mission. Use [Spawn::wait] to wait for the static object
+    /// to be born.
+    pub fn add_static(&self, country: Country, data: &StaticData) -> Result<Spawn<Static>, Error> {
         #[derive(Serialize)]
         struct Params<'a> {
             country: Country,
             data: &'a StaticData,
         }
 
-        let name = data.name.clone();
+        let unborn = std::iter::once(data.name.clone()).collect::<HashSet<_>>();
+
+        // watch for births before adding the static object to not miss any of them
+        let births = self.client.watch_births(unborn.clone())?;
+
+        let added: Added = self
+            .client
+            .request("addStatic", Some(Params { country, data }))?;
+        let staticobj = Static::new(self.client.clone(), added.name.as_str());
+
+        Ok(Spawn::new(staticobj, added.name, added.id, unborn, births))
+    }
+
+    /// Enables or disables the server-side tracking of fired weapons. Tracked weapons are
+    /// followed until they disappear, which is reported with either an [Event::WeaponImpact] or
+    /// an [Event::WeaponGone] event.
+    pub fn track_weapons(&self, enabled: bool) -> Result<(), Error> {
+        #[derive(Serialize)]
+        struct Params {
+            enabled: bool,
+        }
+
         self.client
-            .notification("addStatic", Some(Params { country, data }))?;
+            .request::<_, Option<()>>("trackWeapons", Some(Params { enabled }))?;
+        Ok(())
+    }
 
-        let staticobj = Static::new(self.client.clone(), name);
-        let started = Instant::now();
-        while !staticobj.exists()? {
-            if started.elapsed() > Duration::from_secs(1) {
-                return Err(Error::AddStaticTimeout);
-            }
-            thread::sleep(Duration::from_millis(100));
+    /// Schedules the RPC call of `method` (e.g. `outText`, `addGroup` or `setUserFlag`, see the
+    /// JSON-RPC documentation) with the given `params`. The call is executed by the mission at the
+    /// given mission time, which is unaffected by the client disconnecting. Returns the ID of the
+    /// scheduled call, which is used to cancel it via [Client::cancel_scheduled].
+    pub fn schedule<P>(
+        &self,
+        method: &str,
+        params: Option<P>,
+        schedule: Schedule,
+    ) -> Result<u64, Error>
+    where
+        P: serde::Serialize,
+    {
+        #[derive(Serialize)]
+        struct Params<'a, P> {
+            method: &'a str,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            params: Option<P>,
+            schedule: Schedule,
         }
 
-        Ok(staticobj)
+        self.client.request(
+            "schedule",
+            Some(Params {
+                method,
+                params,
+                schedule,
+            }),
+        )
+    }
+
+    /// Returns all scheduled RPC calls that have not been executed yet (including repeated
+    /// ones).
+    pub fn scheduled(&self) -> Result<Vec<ScheduledAction>, Error> {
+        self.client
+            .request::<(), Vec<ScheduledAction>>("getScheduled", None)
+    }
+
+    /// Cancels the scheduled RPC call with the given `id`. Returns whether the call was still
+    /// scheduled.
+    pub fn cancel_scheduled(&self, id: u64) -> Result<bool, Error> {
+        #[derive(Serialize)]
+        struct Params {
+            id: u64,
+        }
+
+        self.client.request("cancelScheduled", Some(Params { id }))
     }
 
     /// Returns an endless iterator that will yield all future mission events.
     pub fn events(&self) -> Result<EventsIterator<C>, Error> {
         let (tx, rx) = channel::<RawEvent>();
-        self.client.add_subscription(tx);
-
-        self.client.request::<(), String>("subscribe", None)?;
+        self.client.subscribe(tx)?;
 
         Ok(EventsIterator {
             client: self.client.clone(),
@@ -286,24 +565,134 @@ where
         crate::menu::add_coalition_command(&self.client, coalition, name, None, command)
     }
 
+    /// Returns all objects of the given `categories` within the given `volume`.
+    pub fn search_objects(
+        &self,
+        categories: &[ObjectCategory],
+        volume: &Volume,
+    ) -> Result<Vec<Object>, Error> {
+        #[derive(Serialize)]
+        struct Params<'a> {
+            categories: &'a [ObjectCategory],
+            volume: &'a Volume,
+        }
+
+        let objects: Vec<RawTarget> = self
+            .client
+            .request("searchObjects", Some(Params { categories, volume }))?;
+        Ok(objects
+            .into_iter()
+            .map(|o| o.into_object(self.client.clone()))
+            .collect())
+    }
+
+    /// Access to terrain queries like heights, surface types and line of sight checks.
+    pub fn land(&self) -> Land {
+        Land::new(self.client.clone())
+    }
+
+    /// Finds a path along roads or railroads between both positions (see [Land::find_path]).
+    pub fn find_path(
+        &self,
+        from: &Position,
+        to: &Position,
+        kind: PathKind,
+    ) -> Result<Option<Path>, Error> {
+        self.land().find_path(from, to, kind)
+    }
+
+    /// Returns the wind at the given position as a vector pointing towards where the wind is
+    /// blowing to (in m/s, see [Wind::from_vector] to convert it into a direction and speed).
+    pub fn wind_at(&self, position: &Position, with_turbulence: bool) -> Result<Vector, Error> {
+        #[derive(Serialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Params<'a> {
+            position: &'a Position,
+            with_turbulence: bool,
+        }
+
+        self.client.request(
+            "atmosphereWind",
+            Some(Params {
+                position,
+                with_turbulence,
+            }),
+        )
+    }
+
+    pub fn temperature_and_pressure_at(
+        &self,
+        position: &Position,
+    ) -> Result<TemperatureAndPressure, Error> {
+        #[derive(Serialize)]
+        struct Params<'a> {
+            position: &'a Position,
+        }
+
+        self.client.request(
+            "atmosphereTemperatureAndPressure",
+            Some(Params { position }),
+        )
+    }
+
+    /// Returns basic information about the mission, like its theatre, weather and the countries of
+    /// each coalition.
+    pub fn mission_info(&self) -> Result<MissionInfo, Error> {
+        self.client.request::<(), MissionInfo>("missionInfo", None)
+    }
+
+    /// Returns the current mission time and the mission's start date, which can also be used to
+    /// convert the time of events into calendar dates and times (see [MissionTime::date_time]).
+    pub fn mission_time(&self) -> Result<MissionTime, Error> {
+        self.client.request::<(), MissionTime>("missionTime", None)
+    }
+
+    /// Returns the trigger zone with the given `name`.
     pub fn zone(&self, name: &str) -> Result<Zone, Error> {
         #[derive(Serialize)]
         struct Params<'a> {
             name: &'a str,
         }
 
-        let mut zone: Option<Zone> = self.client.request("getZone", Some(Params { name }))?;
+        let mut zone: Option<RawZone> = self.client.request("getZone", Some(Params { name }))?;
         match zone.take() {
-            Some(zone) => Ok(zone),
+            Some(zone) => Ok(zone.into_zone(self.client.clone())),
             None => Err(Error::ZoneGone(name.to_string())),
         }
     }
 
+    /// Returns the names of all trigger zones.
     pub fn zones(&self) -> Result<Vec<String>, Error> {
         self.client.request::<(), Vec<String>>("getZones", None)
     }
 
-    pub fn get_user_flag(&self, flag: &str) -> Result<u16, Error> {
+    /// Starts watching the zone with the given `name` for units matching the `filter`. Units
+    /// entering or leaving the zone are reported with [Event::ZoneEnter] and [Event::ZoneLeave]
+    /// events (units that are already inside the zone are reported as entering it). Returns the
+    /// ID of the watch, which is used to stop it via [Client::unwatch_zone].
+    pub fn watch_zone(&self, name: &str, filter: &ZoneFilter) -> Result<u64, Error> {
+        #[derive(Serialize)]
+        struct Params<'a> {
+            zone: &'a str,
+            filter: &'a ZoneFilter,
+        }
+
+        self.client
+            .request::<_, Option<u64>>("watchZone", Some(Params { zone: name, filter }))?
+            .ok_or_else(|| Error::ZoneGone(name.to_string()))
+    }
+
+    /// Stops the zone watch with the given `id` (see [Client::watch_zone]).
+    pub fn unwatch_zone(&self, id: u64) -> Result<(), Error> {
+        #[derive(Serialize)]
+        struct Params {
+            id: u64,
+        }
+
+        self.client.notification("unwatchZone", Some(Params { id }))
+    }
+
+    pub fn get_user_flag(&self, flag: &str) -> Result<i64, Error> {
         #[derive(Serialize)]
         struct Params<'a> {
             flag: &'a str,
@@ -312,23 +701,113 @@ where
         self.client.request("getUserFlag", Some(Params { flag }))
     }
 
-    pub fn set_user_flag(&self, flag: &str, value: u16) -> Result<(), Error> {
+    pub fn set_user_flag(&self, flag: &str, value: i64) -> Result<(), Error> {
         #[derive(Serialize)]
         struct Params<'a> {
             flag: &'a str,
-            value: u16,
+            value: i64,
         }
 
         self.client
             .notification("setUserFlag", Some(Params { flag, value }))
     }
+
+    /// Starts watching the given user `flags`. Changes of their values are reported with
+    /// [Event::FlagChanged] events. Returns an endless iterator that yields the changes of the
+    /// given `flags`.
+    pub fn watch_flags(&self, flags: &[&str]) -> Result<FlagWatch, Error> {
+        #[derive(Serialize)]
+        struct Params<'a> {
+            flags: &'a [&'a str],
+        }
+
+        let (tx, rx) = channel::<RawEvent>();
+        self.client.subscribe(tx)?;
+        self.client
+            .request::<_, Option<()>>("watchFlags", Some(Params { flags }))?;
+
+        Ok(FlagWatch::new(
+            flags.iter().map(|f| f.to_string()).collect(),
+            rx,
+        ))
+    }
+
+    /// Stops watching the given user `flags` (see [Client::watch_flags]).
+    pub fn unwatch_flags(&self, flags: &[&str]) -> Result<(), Error> {
+        #[derive(Serialize)]
+        struct Params<'a> {
+            flags: &'a [&'a str],
+        }
+
+        self.client
+            .notification("unwatchFlags", Some(Params { flags }))
+    }
+}
+
+/// The result of the `addGroup` and `addStatic` requests.
+#[derive(Deserialize)]
+struct Added {
+    name: String,
+    id: u64,
+}
+
+/// Identifies a group, unit or static object either by its name or by its runtime ID.
+/// Serializes into either `{"name": ...}` or `{"id": ...}`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Identifier {
+    Name(String),
+    Id(u64),
+}
+
+impl From<&str> for Identifier {
+    fn from(name: &str) -> Self {
+        Identifier::Name(name.to_string())
+    }
+}
+
+impl From<String> for Identifier {
+    fn from(name: String) -> Self {
+        Identifier::Name(name)
+    }
+}
+
+impl From<u64> for Identifier {
+    fn from(id: u64) -> Self {
+        Identifier::Id(id)
+    }
+}
+
+impl fmt::Display for Identifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Identifier::Name(name) => write!(f, "{}", name),
+            Identifier::Id(id) => write!(f, "#{}", id),
+        }
+    }
 }
 
-#[derive(Debug, Deserialize)]
-pub struct Zone {
-    #[serde(rename = "point")]
+enum_number!(Modulation {
+    AM = 0,
+    FM = 1,
+});
+
+#[derive(Debug, Clone, Serialize)]
+pub struct RadioTransmission {
+    /// The sound file that should be transmitted (must be part of the mission, e.g.
+    /// `l10n/DEFAULT/beacon.ogg`).
+    pub file: String,
+    /// The position the transmission is sent from.
     pub position: Position,
-    pub radius: f64, // in m
+    pub modulation: Modulation,
+    /// Whether the transmission should be repeated until it is stopped.
+    #[serde(rename = "loop")]
+    pub looped: bool,
+    pub frequency: u64, // in Hz
+    pub power: u32,     // in W
+    /// The name of the transmission, used to stop it via [Client::stop_radio_transmission].
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
 }
 
 pub struct EventsIterator<C> {
@@ -0,0 +1,105 @@
+use crate::{Coalition, Country, Date};
+
+/// Basic information about the running mission (see [crate::Client::mission_info]).
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MissionInfo {
+    /// The map the mission takes place on, e.g. `Caucasus`.
+    pub theatre: String,
+    pub description: String,
+    pub sortie: String,
+    pub date: Date,
+    /// The time of day in seconds the mission starts at.
+    pub start_time: f64,
+    pub weather: Weather,
+    pub coalitions: Coalitions,
+}
+
+/// The countries of each coalition.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct Coalitions {
+    pub red: Vec<Country>,
+    pub blue: Vec<Country>,
+    pub neutral: Vec<Country>,
+}
+
+impl Coalitions {
+    /// The coalition the given `country` is part of, if any.
+    pub fn coalition_of(&self, country: Country) -> Option<Coalition> {
+        if self.red.contains(&country) {
+            Some(Coalition::Red)
+        } else if self.blue.contains(&country) {
+            Some(Coalition::Blue)
+        } else if self.neutral.contains(&country) {
+            Some(Coalition::Neutral)
+        } else {
+            None
+        }
+    }
+}
+
+/// The weather as set in the mission editor.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Weather {
+    pub clouds: Clouds,
+    pub wind: WindLayers,
+    /// The visibility in m.
+    pub visibility: f64,
+    /// The QNH in mmHg.
+    pub qnh: f64,
+    /// The temperature at sea level in °C.
+    pub temperature: f64,
+    /// The ground turbulence in m/s.
+    pub ground_turbulence: f64,
+    /// Only set if fog is enabled.
+    pub fog: Option<Fog>,
+    /// The dust density in m, only set if dust is enabled.
+    pub dust_density: Option<f64>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Clouds {
+    /// The cloud base in m.
+    pub base: f64,
+    /// The thickness of the clouds in m.
+    pub thickness: f64,
+    /// The density from 0 (clear) to 10 (overcast), not used by cloud presets.
+    pub density: u32,
+    pub precipitation: Precipitation,
+    /// The name of the cloud preset, e.g. `Preset7`.
+    pub preset: Option<String>,
+}
+
+enum_number!(Precipitation {
+    None = 0,
+    Rain = 1,
+    Thunderstorm = 2,
+    Snow = 3,
+    Snowstorm = 4,
+});
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WindLayers {
+    pub at_ground: WindLayer,
+    pub at2000: WindLayer,
+    pub at8000: WindLayer,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct WindLayer {
+    /// The wind speed in m/s.
+    pub speed: f64,
+    /// The direction in degrees the wind is blowing towards (as stored by the mission editor,
+    /// which displays the opposite direction).
+    pub direction: f64,
+}
+
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct Fog {
+    /// The thickness of the fog in m.
+    pub thickness: f64,
+    /// The visibility inside the fog in m.
+    pub visibility: f64,
+}
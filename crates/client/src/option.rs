@@ -0,0 +1,536 @@
+use crate::{Error, GroupCategory, OptionParams, UnitCategory};
+use serde_json::Value;
+
+// The ids and values below mirror `AI.Option.Air`, `AI.Option.Ground` and `AI.Option.Naval` of
+// the mission environment (see `missionenv.txt`, which is checked by the tests below).
+
+enum_number!(Roe {
+    WeaponFree = 0,
+    OpenFireWeaponFree = 1,
+    OpenFire = 2,
+    ReturnFire = 3,
+    WeaponHold = 4,
+});
+
+enum_number!(ReactionOnThreat {
+    NoReaction = 0,
+    PassiveDefence = 1,
+    EvadeFire = 2,
+    BypassAndEscape = 3,
+    AllowAbortMission = 4,
+});
+
+enum_number!(RadarUsing {
+    Never = 0,
+    ForAttackOnly = 1,
+    ForSearchIfRequired = 2,
+    ForContinuousSearch = 3,
+});
+
+enum_number!(FlareUsing {
+    Never = 0,
+    AgainstFiredMissile = 1,
+    WhenFlyingInSamWez = 2,
+    WhenFlyingNearEnemies = 3,
+});
+
+enum_number!(EcmUsing {
+    NeverUse = 0,
+    UseIfOnlyLockByRadar = 1,
+    UseIfDetectedLockByRadar = 2,
+    AlwaysUse = 3,
+});
+
+enum_number!(MissileAttack {
+    MaxRange = 0,
+    NezRange = 1,
+    HalfWayRmaxNez = 2,
+    TargetThreatEst = 3,
+    RandomRange = 4,
+});
+
+enum_number!(AlarmState {
+    Auto = 0,
+    Green = 1,
+    Red = 2,
+});
+
+/// Options of airplanes and helicopters (`AI.Option.Air`).
+#[derive(Debug, Clone, PartialEq)]
+pub enum AirOption {
+    Roe(Roe),
+    ReactionOnThreat(ReactionOnThreat),
+    RadarUsing(RadarUsing),
+    FlareUsing(FlareUsing),
+    /// The raw formation value as used by the mission editor.
+    Formation(u64),
+    RtbOnBingo(bool),
+    Silence(bool),
+    /// The weapon flags that define when the group is considered out of ammo.
+    RtbOnOutOfAmmo(u64),
+    EcmUsing(EcmUsing),
+    ProhibitAA(bool),
+    ProhibitJett(bool),
+    ProhibitAB(bool),
+    ProhibitAG(bool),
+    MissileAttack(MissileAttack),
+    ProhibitWpPassReport(bool),
+    /// The target attributes the group reports contacts for.
+    RadioUsageContact(Vec<String>),
+    /// The target attributes the group reports engagements for.
+    RadioUsageEngage(Vec<String>),
+    /// The target attributes the group reports kills for.
+    RadioUsageKill(Vec<String>),
+}
+
+/// Options of ground units and trains (`AI.Option.Ground`).
+#[derive(Debug, Clone, PartialEq)]
+pub enum GroundOption {
+    /// Only [Roe::OpenFire], [Roe::ReturnFire] and [Roe::WeaponHold] are supported.
+    Roe(Roe),
+    /// The raw formation value as used by the mission editor.
+    Formation(u64),
+    /// The time in seconds the group disperses when being attacked (`0` disables dispersing).
+    DisperseOnAttack(u64),
+    AlarmState(AlarmState),
+    EngageAirWeapons(bool),
+}
+
+/// Options of ships (`AI.Option.Naval`).
+#[derive(Debug, Clone, PartialEq)]
+pub enum NavalOption {
+    /// Only [Roe::OpenFire], [Roe::ReturnFire] and [Roe::WeaponHold] are supported.
+    Roe(Roe),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum AiOption {
+    Air(AirOption),
+    Ground(GroundOption),
+    Naval(NavalOption),
+}
+
+impl AirOption {
+    pub fn id(&self) -> u64 {
+        use self::AirOption::*;
+
+        match self {
+            Roe(_) => 0,
+            ReactionOnThreat(_) => 1,
+            RadarUsing(_) => 3,
+            FlareUsing(_) => 4,
+            Formation(_) => 5,
+            RtbOnBingo(_) => 6,
+            Silence(_) => 7,
+            RtbOnOutOfAmmo(_) => 10,
+            EcmUsing(_) => 13,
+            ProhibitAA(_) => 14,
+            ProhibitJett(_) => 15,
+            ProhibitAB(_) => 16,
+            ProhibitAG(_) => 17,
+            MissileAttack(_) => 18,
+            ProhibitWpPassReport(_) => 19,
+            RadioUsageContact(_) => 21,
+            RadioUsageEngage(_) => 22,
+            RadioUsageKill(_) => 23,
+        }
+    }
+
+    pub fn value(&self) -> Value {
+        use self::AirOption::*;
+
+        match self {
+            Roe(v) => Value::from(*v as u64),
+            ReactionOnThreat(v) => Value::from(*v as u64),
+            RadarUsing(v) => Value::from(*v as u64),
+            FlareUsing(v) => Value::from(*v as u64),
+            Formation(v) | RtbOnOutOfAmmo(v) => Value::from(*v),
+            RtbOnBingo(v)
+            | Silence(v)
+            | ProhibitAA(v)
+            | ProhibitJett(v)
+            | ProhibitAB(v)
+            | ProhibitAG(v)
+            | ProhibitWpPassReport(v) => Value::from(*v),
+            EcmUsing(v) => Value::from(*v as u64),
+            MissileAttack(v) => Value::from(*v as u64),
+            RadioUsageContact(v) | RadioUsageEngage(v) | RadioUsageKill(v) => {
+                Value::from(v.clone())
+            }
+        }
+    }
+}
+
+impl GroundOption {
+    pub fn id(&self) -> u64 {
+        use self::GroundOption::*;
+
+        match self {
+            Roe(_) => 0,
+            Formation(_) => 5,
+            DisperseOnAttack(_) => 8,
+            AlarmState(_) => 9,
+            EngageAirWeapons(_) => 20,
+        }
+    }
+
+    pub fn value(&self) -> Value {
+        use self::GroundOption::*;
+
+        match self {
+            Roe(v) => Value::from(*v as u64),
+            Formation(v) | DisperseOnAttack(v) => Value::from(*v),
+            AlarmState(v) => Value::from(*v as u64),
+            EngageAirWeapons(v) => Value::from(*v),
+        }
+    }
+}
+
+impl NavalOption {
+    pub fn id(&self) -> u64 {
+        match self {
+            NavalOption::Roe(_) => 0,
+        }
+    }
+
+    pub fn value(&self) -> Value {
+        match self {
+            NavalOption::Roe(v) => Value::from(*v as u64),
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum OptionDomain {
+    Air,
+    Ground,
+    Naval,
+}
+
+impl AiOption {
+    pub fn id(&self) -> u64 {
+        match self {
+            AiOption::Air(o) => o.id(),
+            AiOption::Ground(o) => o.id(),
+            AiOption::Naval(o) => o.id(),
+        }
+    }
+
+    pub fn value(&self) -> Value {
+        match self {
+            AiOption::Air(o) => o.value(),
+            AiOption::Ground(o) => o.value(),
+            AiOption::Naval(o) => o.value(),
+        }
+    }
+
+    /// Returns the option in the format expected by [crate::Action::Option].
+    pub fn to_params(&self) -> OptionParams {
+        OptionParams {
+            name: self.id(),
+            value: self.value(),
+        }
+    }
+
+    fn domain(&self) -> OptionDomain {
+        match self {
+            AiOption::Air(_) => OptionDomain::Air,
+            AiOption::Ground(_) => OptionDomain::Ground,
+            AiOption::Naval(_) => OptionDomain::Naval,
+        }
+    }
+
+    fn validate(&self, domain: Option<OptionDomain>, kind: &str) -> Result<(), Error> {
+        if domain != Some(self.domain()) {
+            return Err(Error::InvalidOption(format!(
+                "{:?} is not supported by {}",
+                self, kind
+            )));
+        }
+
+        match self {
+            AiOption::Ground(GroundOption::Roe(roe)) | AiOption::Naval(NavalOption::Roe(roe)) => {
+                match roe {
+                    Roe::OpenFire | Roe::ReturnFire | Roe::WeaponHold => Ok(()),
+                    _ => Err(Error::InvalidOption(format!(
+                        "{:?} is not supported by {}",
+                        self, kind
+                    ))),
+                }
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Returns an error if the option cannot be applied to a group of the given `category`.
+    pub fn validate_for_group(&self, category: GroupCategory) -> Result<(), Error> {
+        let domain = match category {
+            GroupCategory::Airplane | GroupCategory::Helicopter => OptionDomain::Air,
+            GroupCategory::Ground | GroupCategory::Train => OptionDomain::Ground,
+            GroupCategory::Ship => OptionDomain::Naval,
+        };
+        self.validate(Some(domain), &format!("{:?} groups", category))
+    }
+
+    /// Returns an error if the option cannot be applied to a unit of the given `category`.
+    pub fn validate_for_unit(&self, category: UnitCategory) -> Result<(), Error> {
+        let domain = match category {
+            UnitCategory::Airplane | UnitCategory::Helicopter => Some(OptionDomain::Air),
+            UnitCategory::Ground => Some(OptionDomain::Ground),
+            UnitCategory::Ship => Some(OptionDomain::Naval),
+            UnitCategory::Structure => None,
+        };
+        self.validate(domain, &format!("{:?} units", category))
+    }
+}
+
+impl From<AirOption> for AiOption {
+    fn from(option: AirOption) -> Self {
+        AiOption::Air(option)
+    }
+}
+
+impl From<GroundOption> for AiOption {
+    fn from(option: GroundOption) -> Self {
+        AiOption::Ground(option)
+    }
+}
+
+impl From<NavalOption> for AiOption {
+    fn from(option: NavalOption) -> Self {
+        AiOption::Naval(option)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    /// Parses the `AI.Option` table of `missionenv.txt` into a map of
+    /// `"<domain>.<id|val>[.<option>].<name>" => value`.
+    fn mission_env_options() -> HashMap<String, i64> {
+        let env = include_str!("../../../missionenv.txt");
+        let start = env.find("    Option = {").unwrap();
+        let end = env.find("    Skill = {").unwrap();
+
+        let mut entries = HashMap::new();
+        let mut path: Vec<String> = Vec::new();
+        for line in env[start..end].lines().skip(1) {
+            let line = line.trim().trim_end_matches(',');
+            if line.ends_with("= {") {
+                path.push(line.trim_end_matches("= {").trim().to_string());
+            } else if line == "}" {
+                path.pop();
+            } else if let Some(pos) = line.find(" = ") {
+                let key = format!("{}.{}", path.join("."), &line[..pos]);
+                entries.insert(key, line[pos + 3..].parse().unwrap());
+            }
+        }
+        entries
+    }
+
+    #[test]
+    fn option_ids_match_mission_env() {
+        let env = mission_env_options();
+        let air = vec![
+            ("ROE", AirOption::Roe(Roe::WeaponHold)),
+            (
+                "REACTION_ON_THREAT",
+                AirOption::ReactionOnThreat(ReactionOnThreat::NoReaction),
+            ),
+            ("RADAR_USING", AirOption::RadarUsing(RadarUsing::Never)),
+            ("FLARE_USING", AirOption::FlareUsing(FlareUsing::Never)),
+            ("FORMATION", AirOption::Formation(0)),
+            ("RTB_ON_BINGO", AirOption::RtbOnBingo(true)),
+            ("SILENCE", AirOption::Silence(true)),
+            ("RTB_ON_OUT_OF_AMMO", AirOption::RtbOnOutOfAmmo(0)),
+            ("ECM_USING", AirOption::EcmUsing(EcmUsing::NeverUse)),
+            ("PROHIBIT_AA", AirOption::ProhibitAA(true)),
+            ("PROHIBIT_JETT", AirOption::ProhibitJett(true)),
+            ("PROHIBIT_AB", AirOption::ProhibitAB(true)),
+            ("PROHIBIT_AG", AirOption::ProhibitAG(true)),
+            (
+                "MISSILE_ATTACK",
+                AirOption::MissileAttack(MissileAttack::MaxRange),
+            ),
+            (
+                "PROHIBIT_WP_PASS_REPORT",
+                AirOption::ProhibitWpPassReport(true),
+            ),
+            (
+                "OPTION_RADIO_USAGE_CONTACT",
+                AirOption::RadioUsageContact(Vec::new()),
+            ),
+            (
+                "OPTION_RADIO_USAGE_ENGAGE",
+                AirOption::RadioUsageEngage(Vec::new()),
+            ),
+            (
+                "OPTION_RADIO_USAGE_KILL",
+                AirOption::RadioUsageKill(Vec::new()),
+            ),
+        ];
+        for (name, option) in air {
+            assert_eq!(
+                env[&format!("Air.id.{}", name)],
+                option.id() as i64,
+                "{}",
+                name
+            );
+        }
+
+        let ground = vec![
+            ("ROE", GroundOption::Roe(Roe::WeaponHold)),
+            ("FORMATION", GroundOption::Formation(0)),
+            ("DISPERSE_ON_ATTACK", GroundOption::DisperseOnAttack(0)),
+            ("ALARM_STATE", GroundOption::AlarmState(AlarmState::Auto)),
+            ("ENGAGE_AIR_WEAPONS", GroundOption::EngageAirWeapons(true)),
+        ];
+        for (name, option) in ground {
+            assert_eq!(
+                env[&format!("Ground.id.{}", name)],
+                option.id() as i64,
+                "{}",
+                name
+            );
+        }
+
+        assert_eq!(
+            env["Naval.id.ROE"],
+            NavalOption::Roe(Roe::WeaponHold).id() as i64
+        );
+
+        // every option of the mission environment must be covered
+        let count = env
+            .keys()
+            .filter(|k| k.contains(".id.") && !k.ends_with("NO_OPTION"))
+            .count();
+        assert_eq!(count, 18 + 5 + 1);
+    }
+
+    #[test]
+    fn option_values_match_mission_env() {
+        let env = mission_env_options();
+        let values: Vec<(&str, i64)> = vec![
+            ("Air.val.ROE.WEAPON_FREE", Roe::WeaponFree as i64),
+            (
+                "Air.val.ROE.OPEN_FIRE_WEAPON_FREE",
+                Roe::OpenFireWeaponFree as i64,
+            ),
+            ("Air.val.ROE.OPEN_FIRE", Roe::OpenFire as i64),
+            ("Air.val.ROE.RETURN_FIRE", Roe::ReturnFire as i64),
+            ("Air.val.ROE.WEAPON_HOLD", Roe::WeaponHold as i64),
+            ("Ground.val.ROE.OPEN_FIRE", Roe::OpenFire as i64),
+            ("Ground.val.ROE.RETURN_FIRE", Roe::ReturnFire as i64),
+            ("Ground.val.ROE.WEAPON_HOLD", Roe::WeaponHold as i64),
+            ("Naval.val.ROE.OPEN_FIRE", Roe::OpenFire as i64),
+            ("Naval.val.ROE.RETURN_FIRE", Roe::ReturnFire as i64),
+            ("Naval.val.ROE.WEAPON_HOLD", Roe::WeaponHold as i64),
+            (
+                "Air.val.REACTION_ON_THREAT.NO_REACTION",
+                ReactionOnThreat::NoReaction as i64,
+            ),
+            (
+                "Air.val.REACTION_ON_THREAT.PASSIVE_DEFENCE",
+                ReactionOnThreat::PassiveDefence as i64,
+            ),
+            (
+                "Air.val.REACTION_ON_THREAT.EVADE_FIRE",
+                ReactionOnThreat::EvadeFire as i64,
+            ),
+            (
+                "Air.val.REACTION_ON_THREAT.BYPASS_AND_ESCAPE",
+                ReactionOnThreat::BypassAndEscape as i64,
+            ),
+            (
+                "Air.val.REACTION_ON_THREAT.ALLOW_ABORT_MISSION",
+                ReactionOnThreat::AllowAbortMission as i64,
+            ),
+            ("Air.val.RADAR_USING.NEVER", RadarUsing::Never as i64),
+            (
+                "Air.val.RADAR_USING.FOR_ATTACK_ONLY",
+                RadarUsing::ForAttackOnly as i64,
+            ),
+            (
+                "Air.val.RADAR_USING.FOR_SEARCH_IF_REQUIRED",
+                RadarUsing::ForSearchIfRequired as i64,
+            ),
+            (
+                "Air.val.RADAR_USING.FOR_CONTINUOUS_SEARCH",
+                RadarUsing::ForContinuousSearch as i64,
+            ),
+            ("Air.val.FLARE_USING.NEVER", FlareUsing::Never as i64),
+            (
+                "Air.val.FLARE_USING.AGAINST_FIRED_MISSILE",
+                FlareUsing::AgainstFiredMissile as i64,
+            ),
+            (
+                "Air.val.FLARE_USING.WHEN_FLYING_IN_SAM_WEZ",
+                FlareUsing::WhenFlyingInSamWez as i64,
+            ),
+            (
+                "Air.val.FLARE_USING.WHEN_FLYING_NEAR_ENEMIES",
+                FlareUsing::WhenFlyingNearEnemies as i64,
+            ),
+            ("Air.val.ECM_USING.NEVER_USE", EcmUsing::NeverUse as i64),
+            (
+                "Air.val.ECM_USING.USE_IF_ONLY_LOCK_BY_RADAR",
+                EcmUsing::UseIfOnlyLockByRadar as i64,
+            ),
+            (
+                "Air.val.ECM_USING.USE_IF_DETECTED_LOCK_BY_RADAR",
+                EcmUsing::UseIfDetectedLockByRadar as i64,
+            ),
+            ("Air.val.ECM_USING.ALWAYS_USE", EcmUsing::AlwaysUse as i64),
+            (
+                "Air.val.MISSILE_ATTACK.MAX_RANGE",
+                MissileAttack::MaxRange as i64,
+            ),
+            (
+                "Air.val.MISSILE_ATTACK.NEZ_RANGE",
+                MissileAttack::NezRange as i64,
+            ),
+            (
+                "Air.val.MISSILE_ATTACK.HALF_WAY_RMAX_NEZ",
+                MissileAttack::HalfWayRmaxNez as i64,
+            ),
+            (
+                "Air.val.MISSILE_ATTACK.TARGET_THREAT_EST",
+                MissileAttack::TargetThreatEst as i64,
+            ),
+            (
+                "Air.val.MISSILE_ATTACK.RANDOM_RANGE",
+                MissileAttack::RandomRange as i64,
+            ),
+            ("Ground.val.ALARM_STATE.AUTO", AlarmState::Auto as i64),
+            ("Ground.val.ALARM_STATE.GREEN", AlarmState::Green as i64),
+            ("Ground.val.ALARM_STATE.RED", AlarmState::Red as i64),
+        ];
+        for (key, value) in &values {
+            assert_eq!(env[*key], *value, "{}", key);
+        }
+
+        // every value of the mission environment must be covered
+        let count = env.keys().filter(|k| k.contains(".val.")).count();
+        assert_eq!(count, values.len());
+    }
+
+    #[test]
+    fn reject_invalid_combinations() {
+        let option = AiOption::from(AirOption::Roe(Roe::WeaponHold));
+        assert!(option.validate_for_group(GroupCategory::Airplane).is_ok());
+        assert!(option.validate_for_group(GroupCategory::Helicopter).is_ok());
+        assert!(option.validate_for_group(GroupCategory::Ground).is_err());
+        assert!(option.validate_for_unit(UnitCategory::Structure).is_err());
+
+        let option = AiOption::from(GroundOption::Roe(Roe::WeaponFree));
+        assert!(option.validate_for_group(GroupCategory::Ground).is_err());
+        let option = AiOption::from(GroundOption::Roe(Roe::ReturnFire));
+        assert!(option.validate_for_group(GroupCategory::Ground).is_ok());
+        assert!(option.validate_for_group(GroupCategory::Ship).is_err());
+
+        let option = AiOption::from(NavalOption::Roe(Roe::OpenFireWeaponFree));
+        assert!(option.validate_for_group(GroupCategory::Ship).is_err());
+    }
+}
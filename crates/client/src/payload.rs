@@ -0,0 +1,271 @@
+use std::collections::BTreeMap;
+
+use serde::de::{self, Deserializer};
+use serde::ser::Serializer;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// The weapons, fuel and countermeasures an aircraft is loaded with.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Payload {
+    /// The loaded pylons by their station number (starting at 1).
+    #[serde(with = "pylons")]
+    pub pylons: BTreeMap<u32, Pylon>,
+    /// The internal fuel in kg.
+    #[serde(deserialize_with = "number_or_string")]
+    pub fuel: f64,
+    pub flare: u32,
+    pub chaff: u32,
+    /// The gun ammunition in percent.
+    pub gun: u32,
+    #[serde(rename = "ammo_type", skip_serializing_if = "Option::is_none")]
+    pub ammo_type: Option<u32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Pylon {
+    /// The ID of the loaded store, e.g. `{6CEB49FC-DED8-4DED-B053-E1F033FF72D3}` for an
+    /// AIM-9M.
+    #[serde(rename = "CLSID")]
+    pub clsid: String,
+    /// Store specific settings (e.g. fuzes), as set in the mission editor.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub settings: Option<Value>,
+}
+
+impl Payload {
+    pub fn pylon(&self, station: u32) -> Option<&Pylon> {
+        self.pylons.get(&station)
+    }
+
+    pub fn set_pylon<S: Into<String>>(&mut self, station: u32, clsid: S) {
+        self.pylons.insert(
+            station,
+            Pylon {
+                clsid: clsid.into(),
+                settings: None,
+            },
+        );
+    }
+
+    pub fn clear_pylon(&mut self, station: u32) -> Option<Pylon> {
+        self.pylons.remove(&station)
+    }
+}
+
+/// The mission editor stores pylons in a Lua table indexed by station, which arrives either as
+/// an array (with `null` for empty stations) or as an object with numeric keys. They are sent
+/// back as an array.
+mod pylons {
+    use super::*;
+
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Raw {
+        List(Vec<Option<Pylon>>),
+        Map(BTreeMap<String, Pylon>),
+    }
+
+    pub fn serialize<S>(pylons: &BTreeMap<u32, Pylon>, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let len = pylons.keys().next_back().copied().unwrap_or(0) as usize;
+        let mut list: Vec<Option<&Pylon>> = vec![None; len];
+        for (station, pylon) in pylons {
+            if *station > 0 {
+                list[*station as usize - 1] = Some(pylon);
+            }
+        }
+        list.serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<BTreeMap<u32, Pylon>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match Raw::deserialize(deserializer)? {
+            Raw::List(list) => Ok(list
+                .into_iter()
+                .enumerate()
+                .filter_map(|(i, pylon)| pylon.map(|p| (i as u32 + 1, p)))
+                .collect()),
+            Raw::Map(map) => map
+                .into_iter()
+                .map(|(station, pylon)| {
+                    station
+                        .parse()
+                        .map(|station| (station, pylon))
+                        .map_err(|_| de::Error::custom(format!("invalid station {}", station)))
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Some aircraft store their fuel as a string in the mission editor.
+fn number_or_string<'de, D>(deserializer: D) -> Result<f64, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Raw {
+        Number(f64),
+        String(String),
+    }
+
+    match Raw::deserialize(deserializer)? {
+        Raw::Number(n) => Ok(n),
+        Raw::String(s) => s.parse().map_err(de::Error::custom),
+    }
+}
+
+/// The callsign of a unit. Western aircraft have a named callsign (e.g. `Enfield11`), while
+/// others (e.g. russian aircraft) only have a number.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Callsign {
+    Named {
+        /// The index of the callsign's name (e.g. 1 for Enfield), its values depend on the
+        /// aircraft's task.
+        id: u16,
+        flight: u16,
+        number: u16,
+        /// The full callsign, e.g. `Enfield11`.
+        name: String,
+    },
+    Number(u32),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::UnitData;
+    use serde_json::json;
+
+    // as received from `groupData` for aircraft placed in the mission editor
+    fn hornet() -> Value {
+        json!({
+            "alt": 2000.0,
+            "alt_type": "BARO",
+            "callsign": {"id": 2, "flight": 1, "number": 1, "name": "Springfield11"},
+            "heading": 0.4,
+            "livery_id": "VFA-37",
+            "onboard_num": "010",
+            "payload": {
+                "pylons": [
+                    {"CLSID": "{6CEB49FC-DED8-4DED-B053-E1F033FF72D3}"},
+                    {"CLSID": "LAU-115_2*LAU-127_AIM-120C"},
+                    null,
+                    {"CLSID": "{FPU_8A_FUEL_TANK}"},
+                    null,
+                    {"CLSID": "LAU-115_2*LAU-127_AIM-120C"},
+                    null,
+                    null,
+                    {"CLSID": "{6CEB49FC-DED8-4DED-B053-E1F033FF72D3}"}
+                ],
+                "fuel": 4900.0,
+                "flare": 30,
+                "ammo_type": 1,
+                "chaff": 60,
+                "gun": 100
+            },
+            "psi": -0.4,
+            "skill": "High",
+            "speed": 180.0,
+            "type": "FA-18C_hornet",
+            "name": "Hornet-1",
+            "x": -281713.4,
+            "y": 647369.1
+        })
+    }
+
+    fn flanker() -> Value {
+        json!({
+            "alt": 2000.0,
+            "alt_type": "BARO",
+            "callsign": 101,
+            "heading": 0.0,
+            "livery_id": "Air Force Standard",
+            "onboard_num": "10",
+            "payload": {
+                "pylons": [
+                    {"CLSID": "{RKL609_L}"},
+                    {"CLSID": "{FBC29BFE-3D24-4C64-B81D-941239D12249}"},
+                    {"CLSID": "{B4C01D60-A8A3-4237-BD72-CA7655BC0FE9}"}
+                ],
+                "fuel": 5590.18,
+                "flare": 96,
+                "chaff": 96,
+                "gun": 100
+            },
+            "psi": 0.0,
+            "skill": "Excellent",
+            "speed": 200.0,
+            "type": "Su-27",
+            "name": "Flanker-1",
+            "x": 12000.0,
+            "y": 30000.0
+        })
+    }
+
+    #[test]
+    fn named_callsign_round_trip() {
+        let json = hornet();
+        let unit: UnitData = serde_json::from_value(json.clone()).unwrap();
+
+        assert_eq!(
+            unit.callsign,
+            Some(Callsign::Named {
+                id: 2,
+                flight: 1,
+                number: 1,
+                name: "Springfield11".to_string(),
+            })
+        );
+        let payload = unit.payload.as_ref().unwrap();
+        assert_eq!(payload.pylons.len(), 5);
+        assert_eq!(payload.pylon(4).unwrap().clsid, "{FPU_8A_FUEL_TANK}");
+        assert!(payload.pylon(3).is_none());
+        assert_eq!(payload.ammo_type, Some(1));
+
+        assert_eq!(serde_json::to_value(&unit).unwrap(), json);
+    }
+
+    #[test]
+    fn numbered_callsign_round_trip() {
+        let json = flanker();
+        let unit: UnitData = serde_json::from_value(json.clone()).unwrap();
+
+        assert_eq!(unit.callsign, Some(Callsign::Number(101)));
+        let payload = unit.payload.as_ref().unwrap();
+        assert_eq!(payload.pylons.len(), 3);
+        assert_eq!(payload.flare, 96);
+        assert_eq!(payload.ammo_type, None);
+
+        assert_eq!(serde_json::to_value(&unit).unwrap(), json);
+    }
+
+    #[test]
+    fn pylons_as_object_and_fuel_as_string() {
+        let payload: Payload = serde_json::from_value(json!({
+            "pylons": {"2": {"CLSID": "{RKL609_L}"}, "10": {"CLSID": "{RKL609_R}"}},
+            "fuel": "3249",
+            "flare": 60,
+            "chaff": 60,
+            "gun": 100
+        }))
+        .unwrap();
+
+        assert_eq!(payload.fuel, 3249.0);
+        assert_eq!(payload.pylon(2).unwrap().clsid, "{RKL609_L}");
+        assert_eq!(payload.pylon(10).unwrap().clsid, "{RKL609_R}");
+
+        let json = serde_json::to_value(&payload).unwrap();
+        let pylons = json["pylons"].as_array().unwrap();
+        assert_eq!(pylons.len(), 10);
+        assert!(pylons[0].is_null());
+        assert_eq!(pylons[1]["CLSID"], "{RKL609_L}");
+    }
+}
@@ -0,0 +1,548 @@
+use crate::{
+    Airbase, AirbaseCategory, AltitudeType, AttackGroupParams, ComboTaskParams, Error, OrbitKind,
+    OrbitParams, Path, PointData, RouteData, Task, WaypointAction, WaypointType,
+};
+
+/// A speed in m/s.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Speed(f64);
+
+impl Speed {
+    pub fn mps(mps: f64) -> Self {
+        Speed(mps)
+    }
+
+    pub fn knots(knots: f64) -> Self {
+        Speed(knots * 0.514_444)
+    }
+
+    pub fn kmh(kmh: f64) -> Self {
+        Speed(kmh / 3.6)
+    }
+
+    pub fn as_mps(self) -> f64 {
+        self.0
+    }
+}
+
+/// An altitude in m, either above sea level (baro) or above ground level (radio).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Altitude {
+    meters: f64,
+    radio: bool,
+}
+
+impl Altitude {
+    /// Altitude above sea level in m.
+    pub fn meters(meters: f64) -> Self {
+        Altitude {
+            meters,
+            radio: false,
+        }
+    }
+
+    /// Altitude above ground level in m.
+    pub fn meters_agl(meters: f64) -> Self {
+        Altitude {
+            meters,
+            radio: true,
+        }
+    }
+
+    /// Altitude above sea level in ft.
+    pub fn feet(feet: f64) -> Self {
+        Altitude::meters(feet * 0.3048)
+    }
+
+    /// Altitude above ground level in ft.
+    pub fn feet_agl(feet: f64) -> Self {
+        Altitude::meters_agl(feet * 0.3048)
+    }
+
+    pub fn as_meters(self) -> f64 {
+        self.meters
+    }
+
+    pub fn altitude_type(self) -> AltitudeType {
+        if self.radio {
+            AltitudeType::Radio
+        } else {
+            AltitudeType::Baro
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Takeoff {
+    /// Start on the runway with engines running.
+    Runway,
+    /// Start cold from a parking spot.
+    Parking,
+    /// Start hot from a parking spot.
+    ParkingHot,
+}
+
+/// Builds the [RouteData] of aircraft and ground groups, e.g.:
+///
+/// ```ignore
+/// let route = RouteBuilder::new()
+///     .takeoff(&airbase, Takeoff::Parking)?
+///     .turning_point(x, y, Altitude::feet(20_000.0), Speed::knots(350.0))
+///     .orbit(OrbitKind::RaceTrack, Altitude::feet(20_000.0), Speed::knots(300.0))
+///     .build()?;
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct RouteBuilder {
+    points: Vec<PointData>,
+    has_air: bool,
+    has_ground: bool,
+    error: Option<String>,
+}
+
+impl RouteBuilder {
+    pub fn new() -> Self {
+        RouteBuilder::default()
+    }
+
+    /// Adds a takeoff waypoint at the given `airbase` (must be the first waypoint).
+    pub fn takeoff(mut self, airbase: &Airbase, kind: Takeoff) -> Result<Self, Error> {
+        let (kind, action) = match kind {
+            Takeoff::Runway => (WaypointType::TakeOff, WaypointAction::TakeOff),
+            Takeoff::Parking => (WaypointType::TakeOffParking, WaypointAction::TakeOffParking),
+            Takeoff::ParkingHot => (
+                WaypointType::TakeOffParkingHot,
+                WaypointAction::TakeOffParkingHot,
+            ),
+        };
+        let pos = airbase.position()?;
+        let mut point = point(kind, action, pos.x, pos.y, Altitude::meters(pos.alt), 0.0);
+        set_airbase(&mut point, airbase)?;
+        self.points.push(point);
+        self.has_air = true;
+        Ok(self)
+    }
+
+    /// Adds a turning point for aircraft.
+    pub fn turning_point(mut self, x: f64, y: f64, alt: Altitude, speed: Speed) -> Self {
+        self.has_air = true;
+        self.push(
+            WaypointType::TurningPoint,
+            WaypointAction::TurningPoint,
+            x,
+            y,
+            alt,
+            speed,
+        )
+    }
+
+    /// Adds a waypoint for aircraft that has to be flown over (instead of turning before it).
+    pub fn fly_over_point(mut self, x: f64, y: f64, alt: Altitude, speed: Speed) -> Self {
+        self.has_air = true;
+        self.push(
+            WaypointType::TurningPoint,
+            WaypointAction::FlyOverPoint,
+            x,
+            y,
+            alt,
+            speed,
+        )
+    }
+
+    /// Adds a waypoint for ground units and ships.
+    pub fn ground_point(mut self, x: f64, y: f64, speed: Speed, action: WaypointAction) -> Self {
+        self.has_ground = true;
+        self.push(
+            WaypointType::TurningPoint,
+            action,
+            x,
+            y,
+            Altitude::meters_agl(0.0),
+            speed,
+        )
+    }
+
+    /// Adds waypoints for ground units that follow the given road or railroad `path`.
+    pub fn path(mut self, path: &Path, speed: Speed) -> Self {
+        self.has_ground = true;
+        self.points.extend(path.waypoints(speed));
+        self
+    }
+
+    /// Adds a landing waypoint at the given `airbase` (must be the last waypoint).
+    pub fn land(mut self, airbase: &Airbase, speed: Speed) -> Result<Self, Error> {
+        let pos = airbase.position()?;
+        let mut point = point(
+            WaypointType::Land,
+            WaypointAction::Land,
+            pos.x,
+            pos.y,
+            Altitude::meters(pos.alt),
+            speed.as_mps(),
+        );
+        set_airbase(&mut point, airbase)?;
+        self.points.push(point);
+        self.has_air = true;
+        Ok(self)
+    }
+
+    /// Adds an orbit task to the last waypoint.
+    pub fn orbit(self, pattern: OrbitKind, alt: Altitude, speed: Speed) -> Self {
+        self.task(Task::Orbit {
+            enabled: true,
+            number: 0,
+            auto: false,
+            params: OrbitParams {
+                altitude: alt.as_meters(),
+                altitude_edited: true,
+                pattern,
+                speed: speed.as_mps(),
+            },
+        })
+    }
+
+    /// Adds a task to attack the group with the given `group_id` to the last waypoint.
+    pub fn attack_group(self, group_id: usize, weapon_type: usize) -> Self {
+        self.task(Task::AttackGroup {
+            enabled: true,
+            number: 0,
+            auto: false,
+            params: AttackGroupParams {
+                weapon_type,
+                group_id,
+            },
+        })
+    }
+
+    /// Adds the given `task` to the last waypoint. Tasks are numbered in the order they are
+    /// added. If the waypoint has a single task that is not a [Task::ComboTask], both tasks are
+    /// combined into one.
+    pub fn task(mut self, mut task: Task) -> Self {
+        let point = match self.points.last_mut() {
+            Some(point) => point,
+            None => {
+                self.error
+                    .get_or_insert_with(|| "tasks require a preceding waypoint".to_string());
+                return self;
+            }
+        };
+
+        if !matches!(point.task, Task::ComboTask { .. }) {
+            let combo = Task::ComboTask {
+                params: ComboTaskParams { tasks: Vec::new() },
+            };
+            let mut existing = std::mem::replace(&mut point.task, combo);
+            set_task_number(&mut existing, 1);
+            if let Task::ComboTask { params } = &mut point.task {
+                params.tasks.push(existing);
+            }
+        }
+
+        if let Task::ComboTask { params } = &mut point.task {
+            set_task_number(&mut task, params.tasks.len() + 1);
+            params.tasks.push(task);
+        }
+
+        self
+    }
+
+    /// Validates and returns the route.
+    pub fn build(self) -> Result<RouteData, Error> {
+        if let Some(err) = self.error {
+            return Err(Error::InvalidRoute(err));
+        }
+
+        if self.points.is_empty() {
+            return Err(Error::InvalidRoute("route has no waypoints".to_string()));
+        }
+
+        if self.has_ground && self.has_air {
+            return Err(Error::InvalidRoute(
+                "route mixes aircraft and ground waypoints".to_string(),
+            ));
+        }
+
+        let last = self.points.len() - 1;
+        for (i, p) in self.points.iter().enumerate() {
+            match p.kind {
+                WaypointType::TakeOff
+                | WaypointType::TakeOffParking
+                | WaypointType::TakeOffParkingHot
+                | WaypointType::TakeOffGround
+                    if i != 0 =>
+                {
+                    return Err(Error::InvalidRoute(format!(
+                        "takeoff waypoint {} is not the first waypoint",
+                        i + 1
+                    )));
+                }
+                WaypointType::Land if i != last => {
+                    return Err(Error::InvalidRoute(format!(
+                        "waypoints after landing waypoint {} are ignored",
+                        i + 1
+                    )));
+                }
+                _ => {}
+            }
+
+            if p.speed < 0.0 || !p.speed.is_finite() {
+                return Err(Error::InvalidRoute(format!(
+                    "waypoint {} has an invalid speed of {}",
+                    i + 1,
+                    p.speed
+                )));
+            }
+        }
+
+        let mut points = self.points;
+        // the mission editor always locks the ETA of the first waypoint
+        points[0].eta_locked = true;
+
+        Ok(RouteData {
+            points,
+            route_relative_tot: false,
+        })
+    }
+
+    fn push(
+        mut self,
+        kind: WaypointType,
+        action: WaypointAction,
+        x: f64,
+        y: f64,
+        alt: Altitude,
+        speed: Speed,
+    ) -> Self {
+        self.points
+            .push(point(kind, action, x, y, alt, speed.as_mps()));
+        self
+    }
+}
+
+pub(crate) fn point(
+    kind: WaypointType,
+    action: WaypointAction,
+    x: f64,
+    y: f64,
+    alt: Altitude,
+    speed: f64,
+) -> PointData {
+    PointData {
+        eta: 0.0,
+        eta_locked: false,
+        action,
+        alt: alt.as_meters(),
+        alt_type: alt.altitude_type(),
+        name: String::new(),
+        speed,
+        speed_locked: true,
+        task: Task::ComboTask {
+            params: ComboTaskParams { tasks: Vec::new() },
+        },
+        kind,
+        x,
+        y,
+        airdrome_id: None,
+        helipad_id: None,
+    }
+}
+
+/// Airdromes are referenced by their airdrome ID, FARPs and ships by their helipad ID.
+fn set_airbase(point: &mut PointData, airbase: &Airbase) -> Result<(), Error> {
+    let id = airbase.id()?;
+    match airbase.category()? {
+        AirbaseCategory::Airdrome => point.airdrome_id = Some(id),
+        AirbaseCategory::Helipad | AirbaseCategory::Ship => point.helipad_id = Some(id),
+    }
+    Ok(())
+}
+
+fn set_task_number(task: &mut Task, n: usize) {
+    match task {
+        Task::ComboTask { .. } | Task::Mission { .. } => {}
+        Task::EngageTargets { number, .. }
+        | Task::EngageTargetsInZone { number, .. }
+        | Task::EngageGroup { number, .. }
+        | Task::AttackGroup { number, .. }
+        | Task::AttackUnit { number, .. }
+        | Task::WrappedAction { number, .. }
+        | Task::FAC { number, .. }
+        | Task::Orbit { number, .. }
+        | Task::Land { number, .. }
+        | Task::AWACS { number, .. }
+        | Task::Tanker { number, .. }
+        | Task::EWR { number, .. }
+        | Task::FACAttackGroup { number, .. }
+        | Task::EmbarkToTransport { number, .. } => *number = n,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn invalid_route(builder: RouteBuilder) -> String {
+        match builder.build() {
+            Err(Error::InvalidRoute(reason)) => reason,
+            Err(err) => panic!("unexpected error: {}", err),
+            Ok(_) => panic!("route was not rejected"),
+        }
+    }
+
+    fn waypoint(kind: WaypointType, action: WaypointAction) -> PointData {
+        point(kind, action, 0.0, 0.0, Altitude::meters(1000.0), 100.0)
+    }
+
+    fn orbit_task() -> Task {
+        Task::Orbit {
+            enabled: true,
+            number: 0,
+            auto: false,
+            params: OrbitParams {
+                altitude: 1000.0,
+                altitude_edited: true,
+                pattern: OrbitKind::Circle,
+                speed: 100.0,
+            },
+        }
+    }
+
+    fn task_numbers(point: &PointData) -> Vec<usize> {
+        match &point.task {
+            Task::ComboTask { params } => params
+                .tasks
+                .iter()
+                .map(|task| match task {
+                    Task::Orbit { number, .. } | Task::AttackGroup { number, .. } => *number,
+                    _ => panic!("unexpected task"),
+                })
+                .collect(),
+            _ => panic!("waypoint task is not a combo task"),
+        }
+    }
+
+    #[test]
+    fn valid_route() {
+        let route = RouteBuilder::new()
+            .turning_point(0.0, 0.0, Altitude::feet(20_000.0), Speed::knots(350.0))
+            .turning_point(1.0, 1.0, Altitude::feet(20_000.0), Speed::knots(350.0))
+            .build()
+            .unwrap();
+        assert_eq!(route.points.len(), 2);
+        assert!(route.points[0].eta_locked);
+        assert!(!route.points[1].eta_locked);
+    }
+
+    #[test]
+    fn reject_empty_route() {
+        assert_eq!(invalid_route(RouteBuilder::new()), "route has no waypoints");
+    }
+
+    #[test]
+    fn reject_mixed_air_and_ground_waypoints() {
+        let builder = RouteBuilder::new()
+            .turning_point(0.0, 0.0, Altitude::meters(1000.0), Speed::mps(100.0))
+            .ground_point(1.0, 1.0, Speed::mps(10.0), WaypointAction::OffRoad);
+        assert_eq!(
+            invalid_route(builder),
+            "route mixes aircraft and ground waypoints"
+        );
+    }
+
+    #[test]
+    fn reject_takeoff_not_first() {
+        let mut builder = RouteBuilder::new().turning_point(
+            0.0,
+            0.0,
+            Altitude::meters(1000.0),
+            Speed::mps(100.0),
+        );
+        builder.points.push(waypoint(
+            WaypointType::TakeOffParking,
+            WaypointAction::TakeOffParking,
+        ));
+        assert_eq!(
+            invalid_route(builder),
+            "takeoff waypoint 2 is not the first waypoint"
+        );
+    }
+
+    #[test]
+    fn reject_waypoints_after_landing() {
+        let mut builder = RouteBuilder::new();
+        builder
+            .points
+            .push(waypoint(WaypointType::Land, WaypointAction::Land));
+        let builder = builder.turning_point(0.0, 0.0, Altitude::meters(1000.0), Speed::mps(100.0));
+        assert_eq!(
+            invalid_route(builder),
+            "waypoints after landing waypoint 1 are ignored"
+        );
+    }
+
+    #[test]
+    fn reject_negative_speed() {
+        let builder = RouteBuilder::new()
+            .turning_point(0.0, 0.0, Altitude::meters(1000.0), Speed::mps(100.0))
+            .turning_point(1.0, 1.0, Altitude::meters(1000.0), Speed::mps(-1.0));
+        assert_eq!(
+            invalid_route(builder),
+            "waypoint 2 has an invalid speed of -1"
+        );
+    }
+
+    #[test]
+    fn reject_nan_speed() {
+        let builder = RouteBuilder::new().ground_point(
+            0.0,
+            0.0,
+            Speed::mps(f64::NAN),
+            WaypointAction::OffRoad,
+        );
+        assert_eq!(
+            invalid_route(builder),
+            "waypoint 1 has an invalid speed of NaN"
+        );
+    }
+
+    #[test]
+    fn reject_task_without_waypoint() {
+        assert_eq!(
+            invalid_route(RouteBuilder::new().task(orbit_task())),
+            "tasks require a preceding waypoint"
+        );
+    }
+
+    #[test]
+    fn number_tasks_in_order() {
+        let route = RouteBuilder::new()
+            .turning_point(0.0, 0.0, Altitude::meters(1000.0), Speed::mps(100.0))
+            .attack_group(1, 0)
+            .task(orbit_task())
+            .turning_point(1.0, 1.0, Altitude::meters(1000.0), Speed::mps(100.0))
+            .task(orbit_task())
+            .build()
+            .unwrap();
+        assert_eq!(task_numbers(&route.points[0]), vec![1, 2]);
+        assert_eq!(task_numbers(&route.points[1]), vec![1]);
+    }
+
+    #[test]
+    fn combine_single_task_with_added_task() {
+        let mut builder = RouteBuilder::new().turning_point(
+            0.0,
+            0.0,
+            Altitude::meters(1000.0),
+            Speed::mps(100.0),
+        );
+        builder.points[0].task = orbit_task();
+        let route = builder.attack_group(1, 0).build().unwrap();
+        assert_eq!(task_numbers(&route.points[0]), vec![1, 2]);
+        match &route.points[0].task {
+            Task::ComboTask { params } => {
+                assert!(matches!(params.tasks[0], Task::Orbit { .. }));
+                assert!(matches!(params.tasks[1], Task::AttackGroup { .. }));
+            }
+            _ => unreachable!(),
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use serde_json::Value;
+
+/// When a scheduled RPC call is executed (see [crate::Client::schedule]). Times are given in
+/// mission time, so pausing the mission or accelerating time affects them accordingly.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+pub struct Schedule {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    time: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    delay: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    interval: Option<f64>,
+}
+
+impl Schedule {
+    /// Executes the call at the given model `time` (as used by events, see
+    /// [crate::MissionTime::time]).
+    pub fn at(time: f64) -> Self {
+        Schedule {
+            time: Some(time),
+            delay: None,
+            interval: None,
+        }
+    }
+
+    /// Executes the call `delay` seconds of mission time from now.
+    pub fn after(delay: f64) -> Self {
+        Schedule {
+            time: None,
+            delay: Some(delay),
+            interval: None,
+        }
+    }
+
+    /// Repeats the call every `interval` seconds of mission time until it is cancelled.
+    pub fn every(mut self, interval: f64) -> Self {
+        self.interval = Some(interval);
+        self
+    }
+}
+
+/// An RPC call that is scheduled to be executed (see [crate::Client::scheduled]).
+#[derive(Debug, Clone, Deserialize)]
+pub struct ScheduledAction {
+    pub id: u64,
+    pub method: String,
+    #[serde(default)]
+    pub params: Value,
+    /// The model time of the next execution.
+    pub time: f64,
+    /// The interval in seconds the call is repeated at.
+    pub interval: Option<f64>,
+}
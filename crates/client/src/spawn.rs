@@ -0,0 +1,81 @@
+use std::collections::HashSet;
+use std::sync::mpsc::RecvTimeoutError;
+use std::time::{Duration, Instant};
+
+use crate::jsonrpc::Births;
+use crate::Error;
+
+/// A group or static object that was added to the mission (see [crate::Client::add_group] and
+/// [crate::Client::add_static]).
+///
+/// The object can be used right away (e.g. to activate a late activated group), but its units
+/// only exist once they are born, which [Spawn::wait] waits for. The births are recorded until
+/// all units are born or the handle is dropped (or unwrapped via [Spawn::into_inner]).
+pub struct Spawn<T> {
+    object: T,
+    name: String,
+    id: u64,
+    unborn: HashSet<String>,
+    births: Births,
+}
+
+impl<T> Spawn<T> {
+    pub(crate) fn new(
+        object: T,
+        name: String,
+        id: u64,
+        unborn: HashSet<String>,
+        births: Births,
+    ) -> Self {
+        Spawn {
+            object,
+            name,
+            id,
+            unborn,
+            births,
+        }
+    }
+
+    /// The name of the spawned group or static object.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The runtime ID of the spawned group or static object.
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    pub fn get(&self) -> &T {
+        &self.object
+    }
+
+    /// Returns the spawned object without waiting for it to be born, and stops recording its
+    /// births.
+    pub fn into_inner(self) -> T {
+        self.object
+    }
+
+    /// Waits until a birth event has been received for all of the group's units (or for the
+    /// static object). Late activated groups are only born once they are activated. Returns an
+    /// [Error::SpawnTimeout] if this does not happen within the given `timeout`.
+    pub fn wait(mut self, timeout: Duration) -> Result<T, Error> {
+        let deadline = Instant::now() + timeout;
+        while !self.unborn.is_empty() {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            match self.births.recv_timeout(remaining) {
+                Ok(name) => {
+                    self.unborn.remove(&name);
+                }
+                Err(RecvTimeoutError::Timeout) => {
+                    return Err(Error::SpawnTimeout(self.name));
+                }
+                Err(RecvTimeoutError::Disconnected) => {
+                    return Err(Error::Recv(std::sync::mpsc::RecvError));
+                }
+            }
+        }
+
+        Ok(self.object)
+    }
+}
@@ -0,0 +1,392 @@
+use std::collections::HashSet;
+use std::f64::consts::PI;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::mpsc::{channel, RecvTimeoutError};
+use std::sync::Arc;
+use std::thread;
+use std::time::Duration;
+
+use crate::event::RawEvent;
+use crate::{
+    Client, Country, Error, Event, Group, GroupCategory, GroupData, PointData, Skill, Spawn, Task,
+    UnitData,
+};
+use serde_json::Value;
+
+/// How long the respawn thread waits for an event before checking whether it has been stopped.
+const STOP_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Captures the data of a group to spawn copies of it.
+pub struct Template {
+    country: Country,
+    category: GroupCategory,
+    data: GroupData,
+    next: AtomicUsize,
+}
+
+/// Defines how a copy of a [Template] is spawned.
+#[derive(Debug, Clone, Default)]
+pub struct SpawnOptions {
+    name: Option<String>,
+    position: Option<(f64, f64)>,
+    heading: Option<f64>,
+    country: Option<Country>,
+    skill: Option<Skill>,
+}
+
+/// Returned by [Template::respawn_on_death], stops respawning once dropped or stopped.
+pub struct Respawn {
+    stopped: Arc<AtomicBool>,
+}
+
+impl Template {
+    pub fn new(country: Country, category: GroupCategory, data: GroupData) -> Self {
+        Template {
+            country,
+            category,
+            data,
+            next: AtomicUsize::new(1),
+        }
+    }
+
+    /// Captures the given `group`. Only works for groups defined in the Mission Editor (see
+    /// [Group::data]).
+    pub fn from_group(group: &Group) -> Result<Self, Error> {
+        Ok(Template::new(
+            group.country()?,
+            group.category()?,
+            group.data()?,
+        ))
+    }
+
+    pub fn name(&self) -> &str {
+        group_name(&self.data)
+    }
+
+    pub fn data(&self) -> &GroupData {
+        &self.data
+    }
+
+    /// Returns a copy of the template's group data, named `name` and transformed according to
+    /// the given `options` (the option's name is ignored). Units are named `<name>-<n>`.
+    pub fn instantiate(&self, name: &str, options: &SpawnOptions) -> GroupData {
+        let mut data = self.data.clone();
+
+        let (origin_x, origin_y) = match &data {
+            GroupData::Aircraft(d) => (d.x, d.y),
+            GroupData::Ground(d) => (d.x, d.y),
+        };
+        let original_heading = units(&data).first().map(|u| u.heading).unwrap_or(0.0);
+        let rotation = options.heading.map(|h| h - original_heading).unwrap_or(0.0);
+        let (target_x, target_y) = options.position.unwrap_or((origin_x, origin_y));
+        let (sin, cos) = rotation.sin_cos();
+        let transform = |x: &mut f64, y: &mut f64| {
+            let (dx, dy) = (*x - origin_x, *y - origin_y);
+            *x = target_x + dx * cos - dy * sin;
+            *y = target_y + dx * sin + dy * cos;
+        };
+
+        match &mut data {
+            GroupData::Aircraft(d) => {
+                d.name = name.to_string();
+                transform(&mut d.x, &mut d.y);
+            }
+            GroupData::Ground(d) => {
+                d.name = name.to_string();
+                transform(&mut d.x, &mut d.y);
+            }
+        }
+
+        for (i, unit) in units_mut(&mut data).iter_mut().enumerate() {
+            unit.name = format!("{}-{}", name, i + 1);
+            transform(&mut unit.x, &mut unit.y);
+            unit.heading = (unit.heading + rotation).rem_euclid(2.0 * PI);
+            if let Some(psi) = unit.psi.as_mut() {
+                *psi -= rotation;
+            }
+            if let Some(skill) = &options.skill {
+                unit.skill = Some(skill.clone());
+            }
+        }
+
+        for point in points_mut(&mut data).iter_mut() {
+            transform(&mut point.x, &mut point.y);
+            transform_task(&mut point.task, &transform);
+        }
+
+        data
+    }
+
+    /// Spawns a copy of the template. Unless a name is set in `options`, the group is named
+    /// `<template name> #<n>` where `n` is the next number that is not used by an existing group.
+    pub fn spawn<C>(
+        &self,
+        client: &Client<C>,
+        options: &SpawnOptions,
+    ) -> Result<Spawn<Group>, Error>
+    where
+        for<'de> C: serde::Serialize + serde::Deserialize<'de>,
+    {
+        let name = match &options.name {
+            Some(name) => name.clone(),
+            None => loop {
+                let n = self.next.fetch_add(1, Ordering::Relaxed);
+                let name = format!("{} #{:03}", self.name(), n);
+                if !client.group_unchecked(&name).exists()? {
+                    break name;
+                }
+            },
+        };
+
+        let data = self.instantiate(&name, options);
+        client.add_group(
+            options.country.unwrap_or(self.country),
+            self.category,
+            &data,
+        )
+    }
+
+    /// Respawns the original group (unchanged and under its original name) every time all of
+    /// its units are dead.
+    pub fn respawn_on_death<C>(self, client: &Client<C>) -> Result<Respawn, Error>
+    where
+        for<'de> C: serde::Serialize + serde::Deserialize<'de>,
+    {
+        let client: Client<Value> = Client {
+            client: client.client.clone(),
+            mark: std::marker::PhantomData,
+        };
+        let (tx, rx) = channel::<RawEvent>();
+        client.client.subscribe(tx)?;
+        let stopped = Arc::new(AtomicBool::new(false));
+
+        let stopped2 = stopped.clone();
+        thread::spawn(move || {
+            let mut alive = unit_names(&self.data);
+            // wake up regularly to notice a stop request even if no events are received
+            while !stopped2.load(Ordering::Relaxed) {
+                let event = match rx.recv_timeout(STOP_POLL_INTERVAL) {
+                    Ok(event) => event,
+                    Err(RecvTimeoutError::Timeout) => continue,
+                    Err(RecvTimeoutError::Disconnected) => break,
+                };
+                let event = match event.into_event::<Value>(client.client.clone()) {
+                    Ok(event) => event,
+                    Err(err) => {
+                        error!("Error deserializing event: {}", err);
+                        continue;
+                    }
+                };
+
+                let unit = match event {
+                    Event::Dead { initiator, .. } | Event::Crash { initiator, .. } => initiator,
+                    _ => continue,
+                };
+                if !alive.remove(unit.name()) || !alive.is_empty() {
+                    continue;
+                }
+
+                info!("Respawning {}", self.name());
+                if let Err(err) = client.add_group(self.country, self.category, &self.data) {
+                    error!("Error respawning {}: {}", self.name(), err);
+                }
+                alive = unit_names(&self.data);
+            }
+        });
+
+        Ok(Respawn { stopped })
+    }
+}
+
+impl SpawnOptions {
+    pub fn new() -> Self {
+        SpawnOptions::default()
+    }
+
+    /// The name of the spawned group (must be unique).
+    pub fn name<N: Into<String>>(mut self, name: N) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    /// Moves the whole group (including its route) so that the group's origin is at the given
+    /// `x` and `y` coordinates.
+    pub fn at(mut self, x: f64, y: f64) -> Self {
+        self.position = Some((x, y));
+        self
+    }
+
+    /// Rotates the whole group (including its route) around the group's origin so that its
+    /// first unit faces the given `heading` (in radians, normalized to 0..2π).
+    pub fn heading(mut self, heading: f64) -> Self {
+        self.heading = Some(heading);
+        self
+    }
+
+    pub fn country(mut self, country: Country) -> Self {
+        self.country = Some(country);
+        self
+    }
+
+    /// Overrides the skill of all units.
+    pub fn skill(mut self, skill: Skill) -> Self {
+        self.skill = Some(skill);
+        self
+    }
+}
+
+impl Respawn {
+    /// Stops respawning (takes effect within half a second).
+    pub fn stop(&self) {
+        self.stopped.store(true, Ordering::Relaxed);
+    }
+}
+
+impl Drop for Respawn {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+fn group_name(data: &GroupData) -> &str {
+    match data {
+        GroupData::Aircraft(d) => &d.name,
+        GroupData::Ground(d) => &d.name,
+    }
+}
+
+fn units(data: &GroupData) -> &[UnitData] {
+    match data {
+        GroupData::Aircraft(d) => &d.units,
+        GroupData::Ground(d) => &d.units,
+    }
+}
+
+fn units_mut(data: &mut GroupData) -> &mut [UnitData] {
+    match data {
+        GroupData::Aircraft(d) => &mut d.units,
+        GroupData::Ground(d) => &mut d.units,
+    }
+}
+
+fn points_mut(data: &mut GroupData) -> &mut [PointData] {
+    match data {
+        GroupData::Aircraft(d) => &mut d.route.points,
+        GroupData::Ground(d) => &mut d.route.points,
+    }
+}
+
+fn unit_names(data: &GroupData) -> HashSet<String> {
+    units(data).iter().map(|u| u.name.clone()).collect()
+}
+
+fn transform_task<F: Fn(&mut f64, &mut f64)>(task: &mut Task, transform: &F) {
+    match task {
+        Task::ComboTask { params } => {
+            for task in params.tasks.iter_mut() {
+                transform_task(task, transform);
+            }
+        }
+        Task::EngageTargetsInZone { params, .. } => transform(&mut params.x, &mut params.y),
+        Task::Land { params, .. } => transform(&mut params.x, &mut params.y),
+        Task::EmbarkToTransport { params, .. } => transform(&mut params.x, &mut params.y),
+        _ => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f64::consts::{FRAC_PI_2, FRAC_PI_4};
+
+    fn template() -> Template {
+        let data: GroupData = serde_json::from_value(serde_json::json!({
+            "hidden": false,
+            "name": "Convoy",
+            "route": {
+                "points": [{
+                    "ETA": 0, "ETA_locked": true, "action": "Off Road", "alt": 0,
+                    "alt_type": "BARO", "name": "", "speed": 5, "speed_locked": true,
+                    "task": {"id": "ComboTask", "params": {"tasks": []}},
+                    "type": "Turning Point", "x": 100, "y": 200
+                }, {
+                    "ETA": 0, "ETA_locked": false, "action": "Off Road", "alt": 0,
+                    "alt_type": "BARO", "name": "", "speed": 5, "speed_locked": true,
+                    "task": {"id": "ComboTask", "params": {"tasks": []}},
+                    "type": "Turning Point", "x": 1100, "y": 200
+                }]
+            },
+            "start_time": 0,
+            "tasks": {},
+            "uncontrollable": false,
+            "units": [
+                {"type": "M1043 HMMWV Armament", "name": "Convoy-1", "heading": 0, "psi": 0,
+                 "skill": "Average", "x": 100, "y": 200, "payload": null},
+                {"type": "M1043 HMMWV Armament", "name": "Convoy-2", "heading": 0, "psi": 0,
+                 "skill": "Average", "x": 90, "y": 200, "payload": null}
+            ],
+            "visible": false,
+            "x": 100,
+            "y": 200
+        }))
+        .unwrap();
+        Template::new(Country::USA, GroupCategory::Ground, data)
+    }
+
+    fn assert_close(a: f64, b: f64) {
+        assert!((a - b).abs() < 1e-6, "{} != {}", a, b);
+    }
+
+    #[test]
+    fn instantiate_renames_and_transforms() {
+        let tpl = template();
+        let options = SpawnOptions::new()
+            .at(0.0, 0.0)
+            .heading(FRAC_PI_2)
+            .skill(Skill::Excellent);
+        let data = match tpl.instantiate("Convoy #001", &options) {
+            GroupData::Ground(d) => d,
+            _ => unreachable!(),
+        };
+
+        assert_eq!(data.name, "Convoy #001");
+        assert_eq!(data.units[0].name, "Convoy #001-1");
+        assert_eq!(data.units[1].name, "Convoy #001-2");
+        assert!(matches!(data.units[1].skill, Some(Skill::Excellent)));
+
+        // the group's origin is moved to 0/0
+        assert_close(data.x, 0.0);
+        assert_close(data.y, 0.0);
+        assert_close(data.units[0].heading, FRAC_PI_2);
+        assert_close(data.units[0].psi.unwrap(), -FRAC_PI_2);
+
+        // 10m behind (south of) the group's origin becomes 10m west of it
+        assert_close(data.units[1].x, 0.0);
+        assert_close(data.units[1].y, -10.0);
+
+        // the route heading north becomes a route heading east
+        assert_close(data.route.points[1].x, 0.0);
+        assert_close(data.route.points[1].y, 1000.0);
+    }
+
+    #[test]
+    fn instantiate_normalizes_headings() {
+        let mut tpl = template();
+        units_mut(&mut tpl.data)[1].heading = FRAC_PI_4;
+        let headings =
+            |heading: f64| match tpl.instantiate("Convoy", &SpawnOptions::new().heading(heading)) {
+                GroupData::Ground(d) => (d.units[0].heading, d.units[1].heading),
+                _ => unreachable!(),
+            };
+
+        // the second unit's heading crosses 0
+        let (first, second) = headings(-FRAC_PI_2);
+        assert_close(first, 3.0 * FRAC_PI_2);
+        assert_close(second, 7.0 * FRAC_PI_4);
+
+        // both headings cross 2π
+        let (first, second) = headings(2.0 * PI + FRAC_PI_2);
+        assert_close(first, FRAC_PI_2);
+        assert_close(second, 3.0 * FRAC_PI_4);
+    }
+}
@@ -0,0 +1,141 @@
+use std::fmt;
+
+/// The current mission time (see [crate::Client::mission_time]).
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MissionTime {
+    /// The model time in seconds since the mission started, as used by all events. It does not
+    /// advance while the mission is paused.
+    pub time: f64,
+    /// The time of day in seconds since midnight of the mission's start date (exceeds 86400 once
+    /// the mission runs past midnight).
+    pub abs_time: f64,
+    /// The time of day in seconds at which the mission started.
+    pub time0: f64,
+    /// The mission's start date.
+    pub date: Date,
+}
+
+impl MissionTime {
+    /// Converts the given model `time` (e.g. of an event) into a calendar date and time.
+    pub fn date_time(&self, time: f64) -> DateTime {
+        let abs = self.time0 + time;
+        let days = (abs / 86_400.0).floor();
+        let seconds = abs - days * 86_400.0;
+
+        DateTime {
+            date: self.date.add_days(days as i64),
+            hour: (seconds / 3600.0) as u32,
+            minute: (seconds % 3600.0 / 60.0) as u32,
+            second: (seconds % 60.0) as u32,
+        }
+    }
+
+    /// The current calendar date and time of the mission.
+    pub fn now(&self) -> DateTime {
+        self.date_time(self.time)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Deserialize)]
+pub struct Date {
+    pub year: i32,
+    pub month: u32,
+    pub day: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct DateTime {
+    pub date: Date,
+    pub hour: u32,
+    pub minute: u32,
+    pub second: u32,
+}
+
+impl Date {
+    /// Returns the date the given number of `days` later (or earlier if negative).
+    pub fn add_days(self, days: i64) -> Date {
+        Date::from_days(self.to_days() + days)
+    }
+
+    // Days since 1970-01-01, see http://howardhinnant.github.io/date_algorithms.html
+    fn to_days(self) -> i64 {
+        let y = i64::from(self.year) - if self.month <= 2 { 1 } else { 0 };
+        let m = i64::from(self.month);
+        let d = i64::from(self.day);
+        let era = if y >= 0 { y } else { y - 399 } / 400;
+        let yoe = y - era * 400;
+        let doy = (153 * (if m > 2 { m - 3 } else { m + 9 }) + 2) / 5 + d - 1;
+        let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+        era * 146_097 + doe - 719_468
+    }
+
+    fn from_days(days: i64) -> Date {
+        let z = days + 719_468;
+        let era = if z >= 0 { z } else { z - 146_096 } / 146_097;
+        let doe = z - era * 146_097;
+        let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146_096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = doy - (153 * mp + 2) / 5 + 1;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 };
+        let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+
+        Date {
+            year: year as i32,
+            month: month as u32,
+            day: day as u32,
+        }
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+impl fmt::Display for DateTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {:02}:{:02}:{:02}",
+            self.date, self.hour, self.minute, self.second
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> Date {
+        Date { year, month, day }
+    }
+
+    #[test]
+    fn add_days() {
+        assert_eq!(date(2016, 6, 21).add_days(0), date(2016, 6, 21));
+        assert_eq!(date(2016, 6, 30).add_days(1), date(2016, 7, 1));
+        assert_eq!(date(2016, 2, 28).add_days(1), date(2016, 2, 29));
+        assert_eq!(date(2015, 2, 28).add_days(1), date(2015, 3, 1));
+        assert_eq!(date(1999, 12, 31).add_days(1), date(2000, 1, 1));
+        assert_eq!(date(2000, 1, 1).add_days(-1), date(1999, 12, 31));
+        assert_eq!(date(1944, 6, 6).add_days(366), date(1945, 6, 7));
+    }
+
+    #[test]
+    fn event_time_to_date_time() {
+        let time = MissionTime {
+            time: 0.0,
+            abs_time: 82_800.0,
+            time0: 82_800.0, // 23:00
+            date: date(2011, 12, 31),
+        };
+
+        assert_eq!(time.now().to_string(), "2011-12-31 23:00:00");
+        assert_eq!(time.date_time(3599.5).to_string(), "2011-12-31 23:59:59");
+        assert_eq!(time.date_time(3600.0).to_string(), "2012-01-01 00:00:00");
+        assert_eq!(time.date_time(90_061.0).to_string(), "2012-01-02 00:01:01");
+    }
+}
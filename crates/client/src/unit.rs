@@ -1,9 +1,14 @@
+use std::collections::HashSet;
 use std::fmt;
 use std::hash::{Hash, Hasher};
 
+use crate::controller::{self, Controlled};
 use crate::group::GroupIterator;
 use crate::jsonrpc::Client;
-use crate::{Coalition, Country, Error, Group, Position};
+use crate::{
+    Action, AiOption, Coalition, Country, DetectedTarget, Detection, Error, Group, Position, Task,
+    WeaponDesc,
+};
 
 #[derive(Clone, Serialize)]
 pub struct Unit {
@@ -38,7 +43,7 @@ impl Unit {
     {
         self.client
             .request::<_, Option<R>>(method, Some(&self))?
-            .ok_or_else(|| Error::GroupGone(self.name.clone()))
+            .ok_or_else(|| Error::UnitGone(self.name.clone()))
     }
 
     pub fn exists(&self) -> Result<bool, Error> {
@@ -135,6 +140,86 @@ impl Unit {
         self.request("unitLife")
     }
 
+    /// The initial life of the unit (see [Unit::life]).
+    pub fn life0(&self) -> Result<f64, Error> {
+        self.request("unitLife0")
+    }
+
+    /// The velocity vector in m/s.
+    pub fn velocity(&self) -> Result<Vector, Error> {
+        self.request("unitVelocity")
+    }
+
+    /// The internal fuel in percent of the maximum internal fuel (values above `1.0` mean that
+    /// the unit carries external fuel tanks).
+    pub fn fuel(&self) -> Result<f64, Error> {
+        self.request("unitFuel")
+    }
+
+    /// The ammunition of the unit, one entry per weapon type (guns are not listed for all
+    /// unit types).
+    pub fn ammo(&self) -> Result<Vec<Ammo>, Error> {
+        self.request("unitAmmo")
+    }
+
+    /// The type name, e.g. `F-16C_50`.
+    pub fn type_name(&self) -> Result<String, Error> {
+        self.request("unitTypeName")
+    }
+
+    /// The unit's callsign, e.g. `Enfield11` (or a number for units without named callsigns).
+    pub fn callsign(&self) -> Result<String, Error> {
+        self.request("unitCallsign")
+    }
+
+    /// The unit's position within its group (starting at 1).
+    pub fn number(&self) -> Result<u32, Error> {
+        self.request("unitNumber")
+    }
+
+    pub fn sensors(&self) -> Result<Sensors, Error> {
+        self.request("unitSensors")
+    }
+
+    pub fn has_radar(&self) -> Result<bool, Error> {
+        self.request("unitHasRadar")
+    }
+
+    /// The state of the unit's radar, or `None` if the unit has no radar.
+    pub fn radar(&self) -> Result<Option<Radar>, Error> {
+        if !self.has_radar()? {
+            return Ok(None);
+        }
+        self.request("unitRadar").map(Some)
+    }
+
+    /// Whether the unit is active (i.e. it is not a late activated unit waiting for its
+    /// activation).
+    pub fn is_active(&self) -> Result<bool, Error> {
+        self.request("unitIsActive")
+    }
+
+    /// Describes the unit's type. Descriptions are cached per unit type.
+    pub fn desc(&self) -> Result<UnitDesc, Error> {
+        let type_name = self.type_name()?;
+        if let Some(desc) = self.client.type_descs.lock().unwrap().get(&type_name) {
+            return Ok(desc.clone());
+        }
+
+        let desc: UnitDesc = self.request("unitDesc")?;
+        self.client
+            .type_descs
+            .lock()
+            .unwrap()
+            .insert(type_name, desc.clone());
+        Ok(desc)
+    }
+
+    /// Whether the unit's type has the given `attribute`, e.g. `"SAM SR"` or `"AWACS"`.
+    pub fn has_attribute(&self, attribute: &str) -> Result<bool, Error> {
+        Ok(self.desc()?.attributes.contains(attribute))
+    }
+
     pub fn player_name(&self) -> Result<Option<String>, Error> {
         self.client
             .request::<_, Option<String>>("unitPlayerName", Some(&self))
@@ -156,6 +241,90 @@ impl Unit {
         self.request("unitCategory")
     }
 
+    /// Sets the task of the unit's AI controller, overwriting all existing tasks. Only
+    /// airplanes and helicopters have a unit-level controller, ground units and ships are
+    /// controlled on group-level (see [Group::set_task]).
+    pub fn set_task(&self, task: &Task) -> Result<(), Error> {
+        controller::set_task(&self.client, Controlled::Unit(self), task)
+    }
+
+    /// Pushes the given `task` to the front of the unit's task queue.
+    pub fn push_task(&self, task: &Task) -> Result<(), Error> {
+        controller::push_task(&self.client, Controlled::Unit(self), task)
+    }
+
+    /// Removes the task at the front of the unit's task queue.
+    pub fn pop_task(&self) -> Result<(), Error> {
+        controller::pop_task(&self.client, Controlled::Unit(self))
+    }
+
+    /// Removes all tasks of the unit.
+    pub fn reset_task(&self) -> Result<(), Error> {
+        controller::reset_task(&self.client, Controlled::Unit(self))
+    }
+
+    /// Returns whether the unit currently has a task.
+    pub fn has_task(&self) -> Result<bool, Error> {
+        controller::has_task(&self.client, Controlled::Unit(self))?
+            .ok_or_else(|| Error::UnitGone(self.name.clone()))
+    }
+
+    /// Executes the given `command` immediately (commands are not queued like tasks).
+    pub fn set_command(&self, command: &Action) -> Result<(), Error> {
+        controller::set_command(&self.client, Controlled::Unit(self), command)
+    }
+
+    /// Returns the targets detected by the unit's AI controller with any of the given `detection`
+    /// methods (or with any method if `detection` is empty).
+    pub fn detected_targets(&self, detection: Detection) -> Result<Vec<DetectedTarget>, Error> {
+        controller::detected_targets(&self.client, Controlled::Unit(self), detection)?
+            .ok_or_else(|| Error::UnitGone(self.name.clone()))
+    }
+
+    /// Sets an option of the unit's AI controller. Returns an [Error::InvalidOption] if the
+    /// option is not supported by the unit's category.
+    pub fn set_option<O: Into<AiOption>>(&self, option: O) -> Result<(), Error> {
+        let option = option.into();
+        option.validate_for_unit(self.category()?)?;
+        controller::set_option(&self.client, Controlled::Unit(self), &option.to_params())
+    }
+
+    /// Displays the given `text` to the player of this unit for `display_time` seconds.
+    /// `clear_view` defines whether existing messages will be overwritten (`true`) or whether the
+    /// new message is stacked to existing ones (`false`).
+    pub fn out_text(&self, text: &str, display_time: usize, clear_view: bool) -> Result<(), Error> {
+        #[derive(Serialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Params<'a> {
+            unit: &'a Unit,
+            text: &'a str,
+            display_time: usize,
+            clear_view: bool,
+        }
+
+        self.client.notification(
+            "outTextForUnit",
+            Some(Params {
+                unit: self,
+                text,
+                display_time,
+                clear_view,
+            }),
+        )
+    }
+
+    /// Plays the sound `file` to the player of this unit.
+    pub fn out_sound(&self, file: &str) -> Result<(), Error> {
+        #[derive(Serialize)]
+        struct Params<'a> {
+            unit: &'a Unit,
+            file: &'a str,
+        }
+
+        self.client
+            .notification("outSoundForUnit", Some(Params { unit: self, file }))
+    }
+
     pub fn destroy(self) -> Result<(), Error> {
         self.client.notification("unitDestory", Some(&self))
     }
@@ -175,14 +344,20 @@ impl Hash for Unit {
     }
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub struct Vector {
     pub x: f64,
     pub y: f64,
     pub z: f64,
 }
 
-#[derive(Debug, Deserialize)]
+impl Vector {
+    pub fn length(&self) -> f64 {
+        (self.x * self.x + self.y * self.y + self.z * self.z).sqrt()
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Orientation {
     pub p: Vector,
     pub x: Vector,
@@ -190,6 +365,90 @@ pub struct Orientation {
     pub z: Vector,
 }
 
+#[derive(Debug, Clone, Deserialize)]
+pub struct Ammo {
+    pub count: u32,
+    pub desc: WeaponDesc,
+}
+
+/// The sensors of a unit by their type.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct Sensors {
+    #[serde(default)]
+    pub optic: Vec<Sensor>,
+    #[serde(default)]
+    pub radar: Vec<Sensor>,
+    #[serde(default)]
+    pub irst: Vec<Sensor>,
+    #[serde(default)]
+    pub rwr: Vec<Sensor>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Sensor {
+    #[serde(rename = "typeName")]
+    pub type_name: String,
+    /// The sensor type specific properties, e.g. detection distances.
+    #[serde(flatten)]
+    pub properties: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Radar {
+    /// Whether the radar is on.
+    pub active: bool,
+    /// The name of the unit currently tracked by the radar.
+    pub target: Option<String>,
+}
+
+/// Describes a unit type.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnitDesc {
+    pub type_name: String,
+    pub display_name: String,
+    pub category: UnitCategory,
+    /// The initial life of units of this type.
+    pub life: f64,
+    /// The maximum speed in m/s.
+    pub speed_max: Option<f64>,
+    /// The maximum internal fuel in kg (aircraft only).
+    pub fuel_mass_max: Option<f64>,
+    /// The empty mass in kg (aircraft only).
+    pub mass_empty: Option<f64>,
+    /// The maximum takeoff mass in kg (aircraft only).
+    pub mass_max: Option<f64>,
+    /// The service ceiling in m (aircraft only).
+    #[serde(rename = "Hmax")]
+    pub altitude_max: Option<f64>,
+    /// The range in km (aircraft only).
+    pub range: Option<f64>,
+    /// The type's attributes, e.g. `"Air Defence"`, `"SAM SR"` or `"AWACS"`.
+    #[serde(default)]
+    pub attributes: HashSet<String>,
+}
+
+/// Returns the description of the given unit type, either from the cache or from the server.
+pub(crate) fn type_desc(client: &Client, type_name: &str) -> Result<UnitDesc, Error> {
+    if let Some(desc) = client.type_descs.lock().unwrap().get(type_name) {
+        return Ok(desc.clone());
+    }
+
+    #[derive(Serialize)]
+    #[serde(rename_all = "camelCase")]
+    struct Params<'a> {
+        type_name: &'a str,
+    }
+
+    let desc: UnitDesc = client.request("typeDesc", Some(Params { type_name }))?;
+    client
+        .type_descs
+        .lock()
+        .unwrap()
+        .insert(type_name.to_string(), desc.clone());
+    Ok(desc)
+}
+
 pub struct UnitIterator {
     pub(crate) client: Client,
     pub(crate) unit_names: Vec<String>,
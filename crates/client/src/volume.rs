@@ -0,0 +1,23 @@
+use crate::{Orientation, Position};
+
+/// A volume in 3D space that can be searched for objects (see [crate::Client::search_objects]).
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum Volume {
+    /// A sphere around `center` with the given `radius` in m.
+    Sphere { center: Position, radius: f64 },
+    /// A box spanned by its `min` and `max` corners.
+    Box { min: Position, max: Position },
+    /// A pyramid with its apex at the orientation's position, pointing along the orientation's
+    /// x axis for `length` m (e.g. the field of view of a unit, see [crate::Unit::orientation]).
+    /// The half angles are given in radians.
+    #[serde(rename_all = "camelCase")]
+    Pyramid {
+        orientation: Orientation,
+        length: f64,
+        half_angle_horizontal: f64,
+        half_angle_vertical: f64,
+    },
+    /// The line segment between both positions.
+    Segment { from: Position, to: Position },
+}
@@ -0,0 +1,143 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::jsonrpc::Client;
+use crate::Error;
+
+/// The warehouse of an airbase (see [crate::Airbase::warehouse]).
+#[derive(Clone)]
+pub struct Warehouse {
+    client: Client,
+    airbase: String,
+}
+
+enum_number!(Liquid {
+    JetFuel = 0,
+    Avgas = 1,
+    Mw50 = 2,
+    Diesel = 3,
+});
+
+/// An item stored in a warehouse. Aircraft are identified by their type name (e.g.
+/// `F-16C_50`), weapons by their full name (e.g. `weapons.missiles.AIM_120C`).
+/// Serializes into `{"aircraft": ...}`, `{"weapon": ...}` or `{"liquid": ...}`.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum WarehouseItem {
+    Aircraft(String),
+    Weapon(String),
+    Liquid(Liquid),
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct Inventory {
+    /// The number of aircraft by their type name.
+    pub aircraft: HashMap<String, u32>,
+    /// The number of weapons by their name.
+    pub weapons: HashMap<String, u32>,
+    /// The amount of liquids in kg.
+    pub liquids: Liquids,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Liquids {
+    pub jet_fuel: f64,
+    pub avgas: f64,
+    pub mw50: f64,
+    pub diesel: f64,
+}
+
+impl Warehouse {
+    pub(crate) fn new<N: Into<String>>(client: Client, airbase: N) -> Self {
+        Warehouse {
+            client,
+            airbase: airbase.into(),
+        }
+    }
+
+    /// The name of the warehouse's airbase.
+    pub fn airbase(&self) -> &str {
+        &self.airbase
+    }
+
+    pub fn inventory(&self) -> Result<Inventory, Error> {
+        #[derive(Serialize)]
+        struct Params<'a> {
+            name: &'a str,
+        }
+
+        self.client
+            .request::<_, Option<Inventory>>(
+                "warehouseInventory",
+                Some(Params {
+                    name: &self.airbase,
+                }),
+            )?
+            .ok_or(Error::NonExistent)
+    }
+
+    /// The number of aircraft or weapons, or the amount of a liquid in kg.
+    pub fn item_count(&self, item: &WarehouseItem) -> Result<u32, Error> {
+        #[derive(Serialize)]
+        struct Params<'a> {
+            name: &'a str,
+            item: &'a WarehouseItem,
+        }
+
+        self.client
+            .request::<_, Option<u32>>(
+                "warehouseItemCount",
+                Some(Params {
+                    name: &self.airbase,
+                    item,
+                }),
+            )?
+            .ok_or(Error::NonExistent)
+    }
+
+    /// Adds `amount` aircraft, weapons or kg of a liquid.
+    pub fn add_item(&self, item: &WarehouseItem, amount: u32) -> Result<(), Error> {
+        self.update("warehouseAddItem", item, amount)
+    }
+
+    /// Removes `amount` aircraft, weapons or kg of a liquid.
+    pub fn remove_item(&self, item: &WarehouseItem, amount: u32) -> Result<(), Error> {
+        self.update("warehouseRemoveItem", item, amount)
+    }
+
+    /// Sets the number of aircraft or weapons, or the amount of a liquid in kg.
+    pub fn set_item(&self, item: &WarehouseItem, amount: u32) -> Result<(), Error> {
+        self.update("warehouseSetItem", item, amount)
+    }
+
+    fn update(&self, method: &str, item: &WarehouseItem, amount: u32) -> Result<(), Error> {
+        #[derive(Serialize)]
+        struct Params<'a> {
+            name: &'a str,
+            item: &'a WarehouseItem,
+            amount: u32,
+        }
+
+        self.client.notification(
+            method,
+            Some(Params {
+                name: &self.airbase,
+                item,
+                amount,
+            }),
+        )
+    }
+}
+
+impl fmt::Debug for Warehouse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Warehouse {{ airbase: {} }}", self.airbase)
+    }
+}
+
+impl fmt::Display for Warehouse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Warehouse of {}", self.airbase)
+    }
+}
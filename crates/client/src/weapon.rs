@@ -1,9 +1,14 @@
 use std::fmt;
 
+use crate::event::{Object, RawTarget};
 use crate::jsonrpc::Client;
+use crate::{Error, Position, Unit, Vector};
 
-#[derive(Clone)]
+/// A weapon fired by a unit (see [crate::Event::Shot]). Weapons can only be queried while they
+/// exist, i.e. until they hit something or self-destruct.
+#[derive(Clone, Serialize)]
 pub struct Weapon {
+    #[serde(skip)]
     client: Client,
     id: usize,
 }
@@ -16,6 +21,130 @@ impl Weapon {
     pub fn id(&self) -> usize {
         self.id
     }
+
+    fn request<R>(&self, method: &str) -> Result<R, Error>
+    where
+        for<'de> R: serde::Deserialize<'de>,
+    {
+        self.client
+            .request::<_, Option<R>>(method, Some(&self))?
+            .ok_or_else(|| Error::WeaponGone(self.id.to_string()))
+    }
+
+    pub fn exists(&self) -> Result<bool, Error> {
+        self.client.request("weaponExists", Some(&self))
+    }
+
+    pub fn position(&self) -> Result<Position, Error> {
+        self.request("weaponPosition")
+    }
+
+    /// The velocity vector in m/s.
+    pub fn velocity(&self) -> Result<Vector, Error> {
+        self.request("weaponVelocity")
+    }
+
+    pub fn type_name(&self) -> Result<String, Error> {
+        self.request("weaponTypeName")
+    }
+
+    pub fn desc(&self) -> Result<WeaponDesc, Error> {
+        self.request("weaponDesc")
+    }
+
+    /// The unit that fired the weapon.
+    pub fn launcher(&self) -> Result<Option<Unit>, Error> {
+        #[derive(Deserialize)]
+        struct Launcher {
+            exists: bool,
+            launcher: Option<String>,
+        }
+
+        let res: Launcher = self.client.request("weaponLauncher", Some(&self))?;
+        if !res.exists {
+            return Err(Error::WeaponGone(self.id.to_string()));
+        }
+        Ok(res
+            .launcher
+            .map(|name| Unit::new(self.client.clone(), name)))
+    }
+
+    /// The object the weapon is guided to (only set for guided weapons).
+    pub fn target(&self) -> Result<Option<Object>, Error> {
+        #[derive(Deserialize)]
+        struct Target {
+            exists: bool,
+            target: Option<RawTarget>,
+        }
+
+        let res: Target = self.client.request("weaponTarget", Some(&self))?;
+        if !res.exists {
+            return Err(Error::WeaponGone(self.id.to_string()));
+        }
+        Ok(res
+            .target
+            .map(|target| target.into_object(self.client.clone())))
+    }
+}
+
+enum_number!(WeaponCategory {
+    Shell = 0,
+    Missile = 1,
+    Rocket = 2,
+    Bomb = 3,
+});
+
+enum_number!(MissileCategory {
+    AirToAir = 1,
+    SurfaceToAir = 2,
+    Ballistic = 3,
+    AntiShip = 4,
+    Cruise = 5,
+    Other = 6,
+});
+
+enum_number!(GuidanceType {
+    Ins = 1,
+    Ir = 2,
+    RadarActive = 3,
+    RadarSemiActive = 4,
+    RadarPassive = 5,
+    Tv = 6,
+    Laser = 7,
+    Telecontrol = 8,
+});
+
+enum_number!(WarheadType {
+    ArmorPiercing = 0,
+    HighExplosive = 1,
+    ShapedExplosive = 2,
+});
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Warhead {
+    #[serde(rename = "type")]
+    pub kind: WarheadType,
+    /// The mass of the warhead in kg.
+    pub mass: Option<f64>,
+    /// The mass of the explosive in kg.
+    pub explosive_mass: Option<f64>,
+    /// The caliber in mm.
+    pub caliber: Option<f64>,
+}
+
+/// Describes a weapon type.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WeaponDesc {
+    pub type_name: String,
+    pub display_name: String,
+    pub category: WeaponCategory,
+    /// Only set for missiles.
+    pub missile_category: Option<MissileCategory>,
+    /// Only set for guided weapons.
+    pub guidance: Option<GuidanceType>,
+    pub warhead: Option<Warhead>,
 }
 
 impl fmt::Debug for Weapon {
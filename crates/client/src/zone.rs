@@ -0,0 +1,218 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::jsonrpc::Client;
+use crate::{Coalition, Error, GroupCategory, Position, Unit};
+
+/// A trigger zone placed in the mission editor (see [crate::Client::zone]).
+#[derive(Clone)]
+pub struct Zone {
+    client: Client,
+    pub id: u64,
+    pub name: String,
+    pub shape: ZoneShape,
+    pub color: Color,
+    /// The custom properties set in the mission editor.
+    pub properties: HashMap<String, String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ZoneShape {
+    Circle {
+        center: Position,
+        radius: f64, // in m
+    },
+    /// A zone spanned by four points.
+    Quad { points: [Position; 4] },
+}
+
+/// A color with its components ranging from 0 to 1.
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+pub struct Color {
+    pub r: f64,
+    pub g: f64,
+    pub b: f64,
+    pub a: f64,
+}
+
+/// Selects the units whose entering and leaving of a zone is reported (see
+/// [crate::Client::watch_zone]). Units have to match all filters that are set.
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ZoneFilter {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub coalition: Option<Coalition>,
+    /// The category of the units' groups.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category: Option<GroupCategory>,
+    /// Only watch the units with the given names.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub units: Vec<String>,
+    /// Only watch the units of the groups with the given names.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub groups: Vec<String>,
+    /// Only watch units controlled by players.
+    pub players_only: bool,
+}
+
+#[derive(Deserialize)]
+pub(crate) struct RawZone {
+    id: u64,
+    name: String,
+    center: Position,
+    radius: f64,
+    vertices: Option<[Position; 4]>,
+    color: Color,
+    properties: HashMap<String, String>,
+}
+
+impl RawZone {
+    pub(crate) fn into_zone(self, client: Client) -> Zone {
+        let shape = match self.vertices {
+            Some(points) => ZoneShape::Quad { points },
+            None => ZoneShape::Circle {
+                center: self.center,
+                radius: self.radius,
+            },
+        };
+        Zone {
+            client,
+            id: self.id,
+            name: self.name,
+            shape,
+            color: self.color,
+            properties: self.properties,
+        }
+    }
+}
+
+impl Zone {
+    /// Whether the given position is inside the zone (ignoring its altitude).
+    pub fn contains(&self, position: &Position) -> bool {
+        self.shape.contains(position)
+    }
+
+    /// Returns all active units of the given `coalition` and `category` that are inside the zone.
+    pub fn units_inside(
+        &self,
+        coalition: Coalition,
+        category: Option<GroupCategory>,
+    ) -> Result<Vec<Unit>, Error> {
+        #[derive(Serialize)]
+        struct Params {
+            coalition: Coalition,
+            #[serde(skip_serializing_if = "Option::is_none")]
+            category: Option<GroupCategory>,
+        }
+
+        #[derive(Deserialize)]
+        struct UnitPosition {
+            name: String,
+            position: Position,
+        }
+
+        let units: Vec<UnitPosition> = self.client.request(
+            "getUnitPositions",
+            Some(Params {
+                coalition,
+                category,
+            }),
+        )?;
+        Ok(units
+            .into_iter()
+            .filter(|u| self.contains(&u.position))
+            .map(|u| Unit::new(self.client.clone(), u.name))
+            .collect())
+    }
+}
+
+impl ZoneShape {
+    /// Whether the given position is inside the shape (ignoring its altitude).
+    pub fn contains(&self, position: &Position) -> bool {
+        match self {
+            ZoneShape::Circle { center, radius } => {
+                let dx = position.x - center.x;
+                let dy = position.y - center.y;
+                (dx.powi(2) + dy.powi(2)).sqrt() <= *radius
+            }
+            ZoneShape::Quad { points } => {
+                // cast a ray from the position and count how many edges it crosses
+                let mut inside = false;
+                let mut j = points.len() - 1;
+                for i in 0..points.len() {
+                    let (a, b) = (&points[i], &points[j]);
+                    if (a.y > position.y) != (b.y > position.y)
+                        && position.x < (b.x - a.x) * (position.y - a.y) / (b.y - a.y) + a.x
+                    {
+                        inside = !inside;
+                    }
+                    j = i;
+                }
+                inside
+            }
+        }
+    }
+}
+
+impl fmt::Debug for Zone {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Zone")
+            .field("id", &self.id)
+            .field("name", &self.name)
+            .field("shape", &self.shape)
+            .field("color", &self.color)
+            .field("properties", &self.properties)
+            .finish()
+    }
+}
+
+impl fmt::Display for Zone {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pos(x: f64, y: f64) -> Position {
+        Position { x, y, alt: 0.0 }
+    }
+
+    #[test]
+    fn circle_contains() {
+        let circle = ZoneShape::Circle {
+            center: pos(1000.0, -500.0),
+            radius: 200.0,
+        };
+
+        assert!(circle.contains(&pos(1000.0, -500.0)));
+        assert!(circle.contains(&Position {
+            x: 1100.0,
+            y: -400.0,
+            alt: 3000.0,
+        }));
+        assert!(!circle.contains(&pos(1150.0, -350.0)));
+    }
+
+    #[test]
+    fn quad_contains() {
+        // a rotated, non-rectangular quad
+        let quad = ZoneShape::Quad {
+            points: [
+                pos(0.0, 100.0),
+                pos(100.0, 0.0),
+                pos(0.0, -50.0),
+                pos(-100.0, 0.0),
+            ],
+        };
+
+        assert!(quad.contains(&pos(0.0, 0.0)));
+        assert!(quad.contains(&pos(40.0, 50.0)));
+        assert!(quad.contains(&pos(-20.0, -30.0)));
+        assert!(!quad.contains(&pos(60.0, 50.0)));
+        assert!(!quad.contains(&pos(0.0, -60.0)));
+        assert!(!quad.contains(&pos(200.0, 0.0)));
+    }
+}
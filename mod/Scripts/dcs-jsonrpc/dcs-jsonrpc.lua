@@ -15,9 +15,47 @@ local jsonrpc = require "dcsjsonrpc"
 _G.jsonrpc = jsonrpc
 jsonrpc.start()
 
+-- DCS only provides lookups by name, so objects identified by their runtime ID are searched for
+function groupById(id)
+    for _, side in pairs(coalition.side) do
+        for _, group in pairs(coalition.getGroups(side)) do
+            if tonumber(group:getID()) == id then
+                return group
+            end
+        end
+    end
+    return nil
+end
+
+function unitById(id)
+    for _, side in pairs(coalition.side) do
+        for _, group in pairs(coalition.getGroups(side)) do
+            for _, unit in pairs(group:getUnits()) do
+                if tonumber(unit:getID()) == id then
+                    return unit
+                end
+            end
+        end
+    end
+    return nil
+end
+
+function staticById(id)
+    for _, side in pairs(coalition.side) do
+        for _, staticobj in pairs(coalition.getStaticObjects(side)) do
+            if tonumber(staticobj:getID()) == id then
+                return staticobj
+            end
+        end
+    end
+    return nil
+end
+
 function groupByIdentifier(params)
     if type(params.name) == "string" then
         return Group.getByName(params.name)
+    elseif type(params.id) == "number" then
+        return groupById(params.id)
     else
         return nil
     end
@@ -26,6 +64,8 @@ end
 function unitByIdentifier(params)
     if type(params.name) == "string" then
         return Unit.getByName(params.name)
+    elseif type(params.id) == "number" then
+        return unitById(params.id)
     else
         return nil
     end
@@ -34,6 +74,49 @@ end
 function staticByIdentifier(params)
     if type(params.name) == "string" then
         return StaticObject.getByName(params.name)
+    elseif type(params.id) == "number" then
+        return staticById(params.id)
+    else
+        return nil
+    end
+end
+
+-- DCS provides no lookup for weapons, so fired weapons are remembered by their ID
+local weapons = {}
+local weaponsRemembered = 0
+
+-- weapons are named by their numeric id, returns nil for objects that are named otherwise
+function weapon_id(weapon)
+    return tonumber(weapon:getName())
+end
+
+function remember_weapon(weapon)
+    local id = weapon_id(weapon)
+    if id == nil then
+        return
+    end
+
+    weapons[id] = weapon
+    weaponsRemembered = weaponsRemembered + 1
+
+    -- regularly forget weapons that do not exist anymore
+    if weaponsRemembered % 100 == 0 then
+        for id, w in pairs(weapons) do
+            if not w:isExist() then
+                weapons[id] = nil
+            end
+        end
+    end
+end
+
+function weaponByIdentifier(params)
+    if type(params.id) ~= "number" then
+        return nil
+    end
+
+    local weapon = weapons[params.id]
+    if weapon ~= nil and weapon:isExist() then
+        return weapon
     else
         return nil
     end
@@ -47,6 +130,21 @@ function airbaseByIdentifier(params)
     end
 end
 
+function controllerByIdentifier(params)
+    if type(params.group) == "table" then
+        local group = groupByIdentifier(params.group)
+        if group ~= nil then
+            return group:getController()
+        end
+    elseif type(params.unit) == "table" then
+        local unit = unitByIdentifier(params.unit)
+        if unit ~= nil then
+            return unit:getController()
+        end
+    end
+    return nil
+end
+
 --
 -- RPC methods
 --
@@ -97,6 +195,99 @@ function method_outTextForGroup(params)
     return success(nil)
 end
 
+function method_outTextForCoalition(params)
+    -- TODO: return error on missing params
+    trigger.action.outTextForCoalition(params.coalition, params.text, params.displayTime, params.clearView)
+
+    return success(nil)
+end
+
+function method_outTextForCountry(params)
+    -- TODO: return error on missing params
+    trigger.action.outTextForCountry(params.country, params.text, params.displayTime, params.clearView)
+
+    return success(nil)
+end
+
+function method_outTextForUnit(params)
+    -- TODO: return error on missing params
+    local unit = unitByIdentifier(params.unit)
+    if unit == nil then
+        return success(nil)
+    end
+
+    trigger.action.outTextForUnit(unit:getID(), params.text, params.displayTime, params.clearView)
+
+    return success(nil)
+end
+
+function method_outSound(params)
+    -- TODO: return error on missing params
+    trigger.action.outSound(params.file)
+
+    return success(nil)
+end
+
+function method_outSoundForCoalition(params)
+    -- TODO: return error on missing params
+    trigger.action.outSoundForCoalition(params.coalition, params.file)
+
+    return success(nil)
+end
+
+function method_outSoundForCountry(params)
+    -- TODO: return error on missing params
+    trigger.action.outSoundForCountry(params.country, params.file)
+
+    return success(nil)
+end
+
+function method_outSoundForGroup(params)
+    -- TODO: return error on missing params
+    local group = groupByIdentifier(params.group)
+    if group == nil then
+        return success(nil)
+    end
+
+    trigger.action.outSoundForGroup(group:getID(), params.file)
+
+    return success(nil)
+end
+
+function method_outSoundForUnit(params)
+    -- TODO: return error on missing params
+    local unit = unitByIdentifier(params.unit)
+    if unit == nil then
+        return success(nil)
+    end
+
+    trigger.action.outSoundForUnit(unit:getID(), params.file)
+
+    return success(nil)
+end
+
+function method_radioTransmission(params)
+    -- TODO: return error on missing params
+    trigger.action.radioTransmission(
+        params.file,
+        params.position,
+        params.modulation,
+        params.loop,
+        params.frequency,
+        params.power,
+        params.name
+    )
+
+    return success(nil)
+end
+
+function method_stopRadioTransmission(params)
+    -- TODO: return error on missing params
+    trigger.action.stopRadioTransmission(params.name)
+
+    return success(nil)
+end
+
 function method_removeMark(params)
     -- TODO: return error on missing params
     trigger.action.removeMark(params.id)
@@ -104,25 +295,182 @@ function method_removeMark(params)
     return success(nil)
 end
 
+function zone_to_json(zone)
+    local vertices = nil
+    if zone.type == 2 and zone.verticies ~= nil then
+        vertices = {}
+        for _, v in ipairs(zone.verticies) do
+            table.insert(vertices, { x = v.x, y = land.getHeight({ x = v.x, y = v.y }), z = v.y })
+        end
+    end
+
+    local properties = json:newObject()
+    for _, property in ipairs(zone.properties or {}) do
+        properties[property.key] = property.value
+    end
+
+    local color = zone.color or {}
+    return {
+        id = zone.zoneId,
+        name = zone.name,
+        center = { x = zone.x, y = land.getHeight({ x = zone.x, y = zone.y }), z = zone.y },
+        radius = zone.radius,
+        vertices = vertices,
+        color = { r = color[1] or 1, g = color[2] or 1, b = color[3] or 1, a = color[4] or 0.15 },
+        properties = properties,
+    }
+end
+
+function mission_zone(name)
+    for _, zone in pairs(env.mission.triggers.zones) do
+        if zone.name == name then
+            return zone
+        end
+    end
+    return nil
+end
+
 function method_getZone(params)
     -- TODO: return error on missing params
-    local zone = trigger.misc.getZone(params.name)
+    local zone = mission_zone(params.name)
     if zone == nil then
         return success(nil)
-    else
-        return success(zone)
     end
+    return success(zone_to_json(zone))
 end
 
 function method_getZones(params)
     -- TODO: return error on missing params
     local zones = {}
     for _, zone in pairs(env.mission.triggers.zones) do
-        zones.insert(zones, zone.name)
+        table.insert(zones, zone.name)
     end
     return success(zones)
 end
 
+function method_watchFlags(params)
+    -- TODO: return error on missing params
+    for _, flag in ipairs(params.flags) do
+        if watchedFlags[flag] == nil then
+            watchedFlags[flag] = trigger.misc.getUserFlag(flag)
+        end
+    end
+    return success(nil)
+end
+
+function method_unwatchFlags(params)
+    -- TODO: return error on missing params
+    for _, flag in ipairs(params.flags) do
+        watchedFlags[flag] = nil
+    end
+    return success(nil)
+end
+
+function method_watchZone(params)
+    -- TODO: return error on missing params
+    local zone = mission_zone(params.zone)
+    if zone == nil then
+        return success(nil)
+    end
+
+    local id = nextZoneWatchId
+    nextZoneWatchId = nextZoneWatchId + 1
+    zoneWatches[id] = {
+        zone = zone,
+        filter = params.filter or {},
+        inside = {},
+    }
+    return success(id)
+end
+
+function method_unwatchZone(params)
+    -- TODO: return error on missing params
+    zoneWatches[params.id] = nil
+    return success(nil)
+end
+
+function method_getUnitPositions(params)
+    -- TODO: return error on missing params
+    local units = {}
+    for _, group in pairs(coalition.getGroups(params.coalition, params.category)) do
+        for _, unit in pairs(group:getUnits()) do
+            if unit:isExist() and unit:isActive() then
+                table.insert(units, { name = unit:getName(), position = unit:getPoint() })
+            end
+        end
+    end
+    return success(units)
+end
+
+function method_missionInfo(params)
+    local mission = env.mission
+    local weather = mission.weather
+
+    local function wind_layer(layer)
+        return { speed = layer.speed, direction = layer.dir }
+    end
+
+    local fog = nil
+    if weather.enable_fog then
+        fog = { thickness = weather.fog.thickness, visibility = weather.fog.visibility }
+    end
+    local dustDensity = nil
+    if weather.enable_dust then
+        dustDensity = weather.dust_density
+    end
+
+    local function countries(key)
+        local ids = json:newArray()
+        for _, id in ipairs(mission.coalitions[key] or {}) do
+            table.insert(ids, id)
+        end
+        return ids
+    end
+
+    return success({
+        theatre = mission.theatre,
+        description = dict_value(mission.descriptionText or ""),
+        sortie = dict_value(mission.sortie or ""),
+        date = { year = mission.date.Year, month = mission.date.Month, day = mission.date.Day },
+        startTime = mission.start_time,
+        weather = {
+            clouds = {
+                base = weather.clouds.base,
+                thickness = weather.clouds.thickness,
+                density = weather.clouds.density,
+                precipitation = weather.clouds.iprecptns,
+                preset = weather.clouds.preset,
+            },
+            wind = {
+                atGround = wind_layer(weather.wind.atGround),
+                at2000 = wind_layer(weather.wind.at2000),
+                at8000 = wind_layer(weather.wind.at8000),
+            },
+            visibility = weather.visibility.distance,
+            qnh = weather.qnh,
+            temperature = weather.season.temperature,
+            groundTurbulence = weather.groundTurbulence,
+            fog = fog,
+            dustDensity = dustDensity,
+        },
+        coalitions = {
+            red = countries("red"),
+            blue = countries("blue"),
+            neutral = countries("neutrals"),
+        },
+    })
+end
+
+function method_missionTime(params)
+    local date = env.mission.date
+    return success({
+        time = timer.getTime(),
+        absTime = timer.getAbsTime(),
+        time0 = timer.getTime0(),
+        date = { year = date.Year, month = date.Month, day = date.Day },
+    })
+end
+
 function method_getUserFlag(params)
     -- TODO: return error on missing params
     return success(trigger.misc.getUserFlag(params.flag))
@@ -134,6 +482,100 @@ function method_setUserFlag(params)
     return success(nil)
 end
 
+--
+-- RPC scheduler methods
+--
+
+scheduledActions = {}
+nextScheduledActionId = 1
+
+function run_scheduled(id, time)
+    local action = scheduledActions[id]
+    if action == nil then
+        return nil
+    end
+
+    -- methods might modify their params, so every execution gets its own copy
+    local result = handleScheduled(action.method, deep_copy(action.params))
+    if result.error ~= nil then
+        env.error("[JSONRPC] Error executing scheduled "..action.method..": "..tostring(result.error))
+    end
+
+    if action.interval ~= nil then
+        action.time = time + action.interval
+        return action.time
+    else
+        scheduledActions[id] = nil
+        return nil
+    end
+end
+
+function handleScheduled(method, params)
+    local ok, result = pcall(_G["method_"..method], params)
+    if ok then
+        return result
+    else
+        return error(tostring(result))
+    end
+end
+
+function method_schedule(params)
+    -- TODO: return error on missing params
+    if type(_G["method_"..params.method]) ~= "function" then
+        return error("unsupported method "..params.method)
+    end
+    local interval = params.schedule.interval
+    if interval ~= nil and interval <= 0 then
+        return error("Interval must be positive")
+    end
+
+    local now = timer.getTime()
+    local time = params.schedule.time or now + (params.schedule.delay or 0)
+    time = math.max(time, now + 0.01)
+
+    local id = nextScheduledActionId
+    nextScheduledActionId = nextScheduledActionId + 1
+    local action = {
+        id = id,
+        method = params.method,
+        params = params.params,
+        time = time,
+        interval = interval,
+    }
+    action.timerId = timer.scheduleFunction(function(_, t)
+        return run_scheduled(id, t)
+    end, nil, time)
+    scheduledActions[id] = action
+    return success(id)
+end
+
+function method_getScheduled(params)
+    local actions = {}
+    for id, action in pairs(scheduledActions) do
+        table.insert(actions, {
+            id = id,
+            method = action.method,
+            params = action.params,
+            time = action.time,
+            interval = action.interval,
+        })
+    end
+    table.sort(actions, function(a, b) return a.time < b.time end)
+    return success(actions)
+end
+
+function method_cancelScheduled(params)
+    -- TODO: return error on missing params
+    local action = scheduledActions[params.id]
+    if action == nil then
+        return success(false)
+    end
+
+    timer.removeFunction(action.timerId)
+    scheduledActions[params.id] = nil
+    return success(true)
+end
+
 --
 -- RPC Group methods
 --
@@ -157,6 +599,16 @@ function method_groupID(params)
     end
 end
 
+function method_groupName(params)
+    -- TODO: return error on missing params
+    local group = groupByIdentifier(params)
+    if group == nil then
+        return success(nil)
+    else
+        return success(group:getName())
+    end
+end
+
 function method_groupExists(params)
     -- TODO: return error on missing params
     local group = groupByIdentifier(params)
@@ -187,6 +639,60 @@ function dict_value(key)
     end
 end
 
+-- the mission editor data of all countries of the given coalition
+function coalition_countries(side)
+    local key = "neutrals"
+    if side == coalition.side.RED then
+        key = "red"
+    elseif side == coalition.side.BLUE then
+        key = "blue"
+    end
+
+    local data = env.mission.coalition[key]
+    if data == nil or data.country == nil then
+        return {}
+    end
+    return data.country
+end
+
+function deep_copy(value)
+    if type(value) ~= 'table' then
+        return value
+    end
+    local copy = {}
+    for k, v in pairs(value) do
+        copy[k] = deep_copy(v)
+    end
+    return copy
+end
+
+-- Named callsigns are stored as `{ [1] = 1, [2] = 1, [3] = 1, name = "Enfield11" }`, which cannot
+-- be encoded to JSON as is (mixed keys), so they are sent as
+-- `{ id = 1, flight = 1, number = 1, name = "Enfield11" }` instead.
+function callsign_to_json(callsign)
+    if type(callsign) ~= 'table' then
+        return callsign
+    end
+    return {
+        id = callsign[1],
+        flight = callsign[2],
+        number = callsign[3],
+        name = callsign.name,
+    }
+end
+
+function callsign_from_json(callsign)
+    if type(callsign) ~= 'table' then
+        return callsign
+    end
+    return {
+        [1] = callsign.id,
+        [2] = callsign.flight,
+        [3] = callsign.number,
+        name = callsign.name,
+    }
+end
+
 function method_groupData(params)
     -- TODO: return error on missing params
     local group = groupByIdentifier(params)
@@ -194,12 +700,7 @@ function method_groupData(params)
         return success(nil)
     end
 
-    local countries = {}
-    if group:getCoalition() == coalition.side.RED then
-        countries = env.mission.coalition.red.country
-    else
-        countries = env.mission.coalition.blue.country
-    end
+    local countries = coalition_countries(group:getCoalition())
 
     local id = group:getID()
     for _, country in pairs(countries) do
@@ -208,19 +709,15 @@ function method_groupData(params)
                 if type(category) == 'table' and type(category.group) == 'table' then
                     for _, groupData in pairs(category.group) do
                         if groupData.groupId == id then
-                            groupData.name = dict_value(groupData.name)
-                            groupData.category = Group.getByName(groupData.name):getCategory()
-                            for _, unit in pairs(groupData.units) do
+                            -- copy the data to not alter the mission for subsequent calls
+                            local data = deep_copy(groupData)
+                            data.name = dict_value(data.name)
+                            data.category = Group.getByName(data.name):getCategory()
+                            for _, unit in pairs(data.units) do
                                 unit.name = dict_value(unit.name)
-                                if unit.callsign ~= nil and type(unit.callsign) == 'table' then
-                                    unit.callsignCompat = {
-                                        unit.callsign[1],
-                                        unit.callsign[2],
-                                        unit.callsign[3],
-                                    }
-                                end
+                                unit.callsign = callsign_to_json(unit.callsign)
                             end
-                            return success(groupData)
+                            return success(data)
                         end
                     end
                 end
@@ -268,21 +765,20 @@ end
 
 function method_addGroup(params)
     for _, unit in pairs(params.data.units) do
-        if unit.callsignCompat ~= nil then
-            unit.callsign = {
-                unit.callsignCompat[1],
-                unit.callsignCompat[2],
-                unit.callsignCompat[3],
-            }
-            unit.callsignCompat = nil
-        end
+        unit.callsign = callsign_from_json(unit.callsign)
     end
 
     -- TODO: return error on missing params
-    coalition.addGroup(params.country, params.category, params.data)
-    -- Note: the group does not exist immediately, which is why we cannot do something like
-    -- group:getName() here
-    return success("ok")
+    local group = coalition.addGroup(params.country, params.category, params.data)
+    if group == nil then
+        return error("Failed to add group "..tostring(params.data.name))
+    end
+    -- Note: the group's units are not born immediately, but the group's name and ID are
+    -- already available
+    return success({
+        name = group:getName(),
+        id = tonumber(group:getID()),
+    })
 end
 
 function method_groupActivate(params)
@@ -353,6 +849,16 @@ end
 -- RPC Unit methods
 --
 
+function method_unitName(params)
+    -- TODO: return error on missing params
+    local unit = unitByIdentifier(params)
+    if unit == nil then
+        return success(nil)
+    else
+        return success(unit:getName())
+    end
+end
+
 function method_unitExists(params)
     -- TODO: return error on missing params
     local unit = unitByIdentifier(params)
@@ -541,33 +1047,651 @@ function method_unitCategory(params)
     end
 end
 
-function method_unitDestory(params)
+function method_unitLife0(params)
     -- TODO: return error on missing params
     local unit = unitByIdentifier(params)
-    if unit ~= nil then
-        unit:destroy()
+    if unit == nil then
+        return success(nil)
+    else
+        return success(unit:getLife0())
     end
-    return success(nil)
 end
 
---
--- RPC Airbase methods
---
-
-function method_airbaseExists(params)
+function method_unitVelocity(params)
     -- TODO: return error on missing params
-    local airbase = airbaseByIdentifier(params)
-    return success(airbase ~= nil)
+    local unit = unitByIdentifier(params)
+    if unit == nil then
+        return success(nil)
+    else
+        return success(unit:getVelocity())
+    end
 end
 
-function method_airbasePosition(params)
+function method_unitFuel(params)
     -- TODO: return error on missing params
-    local airbase = airbaseByIdentifier(params)
-    if airbase == nil then
-        return error("Airbase does not exist")
+    local unit = unitByIdentifier(params)
+    if unit == nil then
+        return success(nil)
+    else
+        return success(unit:getFuel())
+    end
+end
+
+function weapon_desc(desc)
+    return {
+        typeName = desc.typeName,
+        displayName = desc.displayName,
+        category = desc.category,
+        missileCategory = desc.missileCategory,
+        guidance = desc.guidance,
+        warhead = desc.warhead and {
+            type = desc.warhead.type,
+            mass = desc.warhead.mass,
+            explosiveMass = desc.warhead.explosiveMass,
+            caliber = desc.warhead.caliber,
+        },
+    }
+end
+
+function method_unitAmmo(params)
+    -- TODO: return error on missing params
+    local unit = unitByIdentifier(params)
+    if unit == nil then
+        return success(nil)
+    end
+
+    local ammo = {}
+    for i, item in ipairs(unit:getAmmo() or {}) do
+        ammo[i] = {
+            count = item.count,
+            desc = weapon_desc(item.desc),
+        }
+    end
+    return success(ammo)
+end
+
+function method_unitTypeName(params)
+    -- TODO: return error on missing params
+    local unit = unitByIdentifier(params)
+    if unit == nil then
+        return success(nil)
+    else
+        return success(unit:getTypeName())
+    end
+end
+
+function method_unitCallsign(params)
+    -- TODO: return error on missing params
+    local unit = unitByIdentifier(params)
+    if unit == nil then
+        return success(nil)
+    else
+        return success(unit:getCallsign())
+    end
+end
+
+function method_unitNumber(params)
+    -- TODO: return error on missing params
+    local unit = unitByIdentifier(params)
+    if unit == nil then
+        return success(nil)
+    else
+        return success(unit:getNumber())
+    end
+end
+
+function method_unitSensors(params)
+    -- TODO: return error on missing params
+    local unit = unitByIdentifier(params)
+    if unit == nil then
+        return success(nil)
+    end
+
+    -- the sensors are indexed by their type (starting at 0), which cannot be encoded as a JSON
+    -- array
+    local sensors = unit:getSensors() or {}
+    return success({
+        optic = sensors[Unit.SensorType.OPTIC],
+        radar = sensors[Unit.SensorType.RADAR],
+        irst = sensors[Unit.SensorType.IRST],
+        rwr = sensors[Unit.SensorType.RWR],
+    })
+end
+
+function method_unitHasRadar(params)
+    -- TODO: return error on missing params
+    local unit = unitByIdentifier(params)
+    if unit == nil then
+        return success(nil)
+    else
+        return success(unit:hasSensors(Unit.SensorType.RADAR))
+    end
+end
+
+function method_unitRadar(params)
+    -- TODO: return error on missing params
+    local unit = unitByIdentifier(params)
+    if unit == nil then
+        return success(nil)
+    end
+
+    local active, target = unit:getRadar()
+    return success({
+        active = active,
+        target = identifier(target),
+    })
+end
+
+function method_unitIsActive(params)
+    -- TODO: return error on missing params
+    local unit = unitByIdentifier(params)
+    if unit == nil then
+        return success(nil)
+    else
+        return success(unit:isActive())
+    end
+end
+
+function unit_desc(desc)
+    local attributes = {}
+    for attribute, enabled in pairs(desc.attributes or {}) do
+        if enabled then
+            table.insert(attributes, attribute)
+        end
+    end
+
+    return {
+        typeName = desc.typeName,
+        displayName = desc.displayName,
+        category = desc.category,
+        life = desc.life,
+        speedMax = desc.speedMax,
+        fuelMassMax = desc.fuelMassMax,
+        massEmpty = desc.massEmpty,
+        massMax = desc.massMax,
+        Hmax = desc.Hmax,
+        range = desc.range,
+        attributes = attributes,
+    }
+end
+
+function method_unitDesc(params)
+    -- TODO: return error on missing params
+    local unit = unitByIdentifier(params)
+    if unit == nil then
+        return success(nil)
+    end
+
+    return success(unit_desc(unit:getDesc()))
+end
+
+function method_typeDesc(params)
+    -- TODO: return error on missing params
+    local ok, desc = pcall(Unit.getDescByName, params.typeName)
+    if not ok or desc == nil then
+        return error("Unknown unit type "..tostring(params.typeName))
+    end
+
+    return success(unit_desc(desc))
+end
+
+function method_unitDestory(params)
+    -- TODO: return error on missing params
+    local unit = unitByIdentifier(params)
+    if unit ~= nil then
+        unit:destroy()
+    end
+    return success(nil)
+end
+
+--
+-- RPC Controller methods
+--
+
+function method_controllerSetTask(params)
+    -- TODO: return error on missing params
+    local controller = controllerByIdentifier(params)
+    if controller == nil then
+        return error("Group or unit does not exist")
+    end
+
+    controller:setTask(params.task)
+
+    return success(nil)
+end
+
+function method_controllerPushTask(params)
+    -- TODO: return error on missing params
+    local controller = controllerByIdentifier(params)
+    if controller == nil then
+        return error("Group or unit does not exist")
+    end
+
+    controller:pushTask(params.task)
+
+    return success(nil)
+end
+
+function method_controllerPopTask(params)
+    -- TODO: return error on missing params
+    local controller = controllerByIdentifier(params)
+    if controller == nil then
+        return error("Group or unit does not exist")
+    end
+
+    controller:popTask()
+
+    return success(nil)
+end
+
+function method_controllerResetTask(params)
+    -- TODO: return error on missing params
+    local controller = controllerByIdentifier(params)
+    if controller == nil then
+        return error("Group or unit does not exist")
+    end
+
+    controller:resetTask()
+
+    return success(nil)
+end
+
+function method_controllerHasTask(params)
+    -- TODO: return error on missing params
+    local controller = controllerByIdentifier(params)
+    if controller == nil then
+        return success(nil)
+    else
+        return success(controller:hasTask())
+    end
+end
+
+function method_controllerSetCommand(params)
+    -- TODO: return error on missing params
+    local controller = controllerByIdentifier(params)
+    if controller == nil then
+        return error("Group or unit does not exist")
+    end
+
+    controller:setCommand(params.command)
+
+    return success(nil)
+end
+
+function method_controllerSetOption(params)
+    -- TODO: return error on missing params
+    local controller = controllerByIdentifier(params)
+    if controller == nil then
+        return error("Group or unit does not exist")
+    end
+
+    controller:setOption(params.name, params.value)
+
+    return success(nil)
+end
+
+function method_controllerDetectedTargets(params)
+    -- TODO: return error on missing params
+    local controller = controllerByIdentifier(params)
+    if controller == nil then
+        return success(nil)
+    end
+
+    local methods = {
+        Controller.Detection.VISUAL,
+        Controller.Detection.OPTIC,
+        Controller.Detection.RADAR,
+        Controller.Detection.IRST,
+        Controller.Detection.RWR,
+        Controller.Detection.DLINK,
+    }
+
+    local targets = {}
+    for _, target in ipairs(controller:getDetectedTargets(unpack(params.detection or {}))) do
+        if target.object ~= nil then
+            -- the detection values are distinct flags, so their sum is the bitwise or
+            local detection = 0
+            for _, method in ipairs(methods) do
+                if controller:isTargetDetected(target.object, method) then
+                    detection = detection + method
+                end
+            end
+            local _, _, lastTime, _, _, lastPos, lastVel = controller:isTargetDetected(target.object)
+
+            table.insert(targets, {
+                object = object_identifier(target.object),
+                visible = target.visible,
+                type = target.type,
+                distance = target.distance,
+                detection = detection,
+                lastTime = lastTime,
+                lastPos = lastPos,
+                lastVel = lastVel,
+            })
+        end
+    end
+    return success(targets)
+end
+
+--
+-- RPC Weapon methods
+--
+
+function method_weaponExists(params)
+    -- TODO: return error on missing params
+    return success(weaponByIdentifier(params) ~= nil)
+end
+
+function method_weaponPosition(params)
+    -- TODO: return error on missing params
+    local weapon = weaponByIdentifier(params)
+    if weapon == nil then
+        return success(nil)
+    else
+        return success(weapon:getPoint())
+    end
+end
+
+function method_weaponVelocity(params)
+    -- TODO: return error on missing params
+    local weapon = weaponByIdentifier(params)
+    if weapon == nil then
+        return success(nil)
+    else
+        return success(weapon:getVelocity())
+    end
+end
+
+function method_weaponTypeName(params)
+    -- TODO: return error on missing params
+    local weapon = weaponByIdentifier(params)
+    if weapon == nil then
+        return success(nil)
+    else
+        return success(weapon:getTypeName())
+    end
+end
+
+function method_weaponDesc(params)
+    -- TODO: return error on missing params
+    local weapon = weaponByIdentifier(params)
+    if weapon == nil then
+        return success(nil)
+    else
+        return success(weapon_desc(weapon:getDesc()))
+    end
+end
+
+function method_weaponLauncher(params)
+    -- TODO: return error on missing params
+    local weapon = weaponByIdentifier(params)
+    if weapon == nil then
+        return success({ exists = false })
+    end
+
+    return success({ exists = true, launcher = identifier(weapon:getLauncher()) })
+end
+
+function method_weaponTarget(params)
+    -- TODO: return error on missing params
+    local weapon = weaponByIdentifier(params)
+    if weapon == nil then
+        return success({ exists = false })
+    end
+
+    local target = weapon:getTarget()
+    if target == nil then
+        return success({ exists = true })
+    end
+    return success({ exists = true, target = object_identifier(target) })
+end
+
+function method_trackWeapons(params)
+    -- TODO: return error on missing params
+    weaponTracking = params.enabled == true
+    if not weaponTracking then
+        trackedWeapons = {}
+    end
+    return success(nil)
+end
+
+--
+-- RPC Airbase methods
+--
+
+function method_getAirbases(params)
+    -- TODO: return error on missing params
+    local airbases
+    if params ~= nil and params.coalition ~= nil then
+        airbases = coalition.getAirbases(params.coalition)
+    else
+        airbases = world.getAirbases()
+    end
+
+    local names = {}
+    for i, airbase in ipairs(airbases) do
+        names[i] = airbase:getName()
+    end
+    return success(names)
+end
+
+function method_airbaseExists(params)
+    -- TODO: return error on missing params
+    local airbase = airbaseByIdentifier(params)
+    return success(airbase ~= nil)
+end
+
+function method_airbaseID(params)
+    -- TODO: return error on missing params
+    local airbase = airbaseByIdentifier(params)
+    if airbase == nil then
+        return success(nil)
+    else
+        return success(tonumber(airbase:getID()))
+    end
+end
+
+function method_airbasePosition(params)
+    -- TODO: return error on missing params
+    local airbase = airbaseByIdentifier(params)
+    if airbase == nil then
+        return error("Airbase does not exist")
+    else
+        return success(airbase:getPoint())
+    end
+end
+
+function method_airbaseCoalition(params)
+    -- TODO: return error on missing params
+    local airbase = airbaseByIdentifier(params)
+    if airbase == nil then
+        return success(nil)
+    else
+        return success(airbase:getCoalition())
+    end
+end
+
+function method_airbaseCategory(params)
+    -- TODO: return error on missing params
+    local airbase = airbaseByIdentifier(params)
+    if airbase == nil then
+        return success(nil)
     else
-        return success(airbase:getPoint())
+        return success(airbase:getDesc().category)
+    end
+end
+
+function method_airbaseCallsign(params)
+    -- TODO: return error on missing params
+    local airbase = airbaseByIdentifier(params)
+    if airbase == nil then
+        return success(nil)
+    else
+        return success(airbase:getCallsign())
+    end
+end
+
+function method_airbaseRunways(params)
+    -- TODO: return error on missing params
+    local airbase = airbaseByIdentifier(params)
+    if airbase == nil then
+        return success(nil)
+    end
+
+    local runways = {}
+    for i, runway in ipairs(airbase:getRunways() or {}) do
+        runways[i] = {
+            name = tostring(runway.Name),
+            course = runway.course,
+            length = runway.length,
+            width = runway.width,
+            position = runway.position,
+        }
+    end
+    return success(runways)
+end
+
+function method_airbaseParking(params)
+    -- TODO: return error on missing params
+    local airbase = airbaseByIdentifier(params)
+    if airbase == nil then
+        return success(nil)
+    end
+
+    local spots = {}
+    for i, spot in ipairs(airbase:getParking(params.availableOnly == true) or {}) do
+        spots[i] = {
+            index = spot.Term_Index,
+            terminalType = spot.Term_Type,
+            position = spot.vTerminalPos,
+            occupied = spot.TO_AC ~= nil and spot.TO_AC ~= false,
+            distanceToRunway = spot.fDistToRW,
+        }
+    end
+    return success(spots)
+end
+
+function method_airbaseSetCoalition(params)
+    -- TODO: return error on missing params
+    local airbase = airbaseByIdentifier(params)
+    if airbase == nil then
+        return error("Airbase does not exist")
+    end
+
+    airbase:setCoalition(params.coalition)
+    return success(nil)
+end
+
+function method_airbaseAutoCapture(params)
+    -- TODO: return error on missing params
+    local airbase = airbaseByIdentifier(params)
+    if airbase == nil then
+        return error("Airbase does not exist")
+    end
+
+    airbase:autoCapture(params.enabled == true)
+    return success(nil)
+end
+
+--
+-- RPC Warehouse methods
+--
+
+function warehouseByIdentifier(params)
+    local airbase = airbaseByIdentifier(params)
+    if airbase == nil then
+        return nil
+    end
+    return airbase:getWarehouse()
+end
+
+-- copies a table that maps item names to counts, ensuring it is encoded as a JSON object
+-- even if it is empty
+function item_counts(items)
+    local counts = json:newObject()
+    for name, count in pairs(items or {}) do
+        counts[name] = count
     end
+    return counts
+end
+
+function method_warehouseInventory(params)
+    -- TODO: return error on missing params
+    local warehouse = warehouseByIdentifier(params)
+    if warehouse == nil then
+        return success(nil)
+    end
+
+    local inventory = warehouse:getInventory()
+    return success({
+        aircraft = item_counts(inventory.aircraft),
+        weapons = item_counts(inventory.weapon),
+        liquids = {
+            jetFuel = warehouse:getLiquidAmount(0),
+            avgas = warehouse:getLiquidAmount(1),
+            mw50 = warehouse:getLiquidAmount(2),
+            diesel = warehouse:getLiquidAmount(3),
+        },
+    })
+end
+
+function method_warehouseItemCount(params)
+    -- TODO: return error on missing params
+    local warehouse = warehouseByIdentifier(params)
+    if warehouse == nil then
+        return success(nil)
+    end
+
+    if params.item.liquid ~= nil then
+        return success(math.floor(warehouse:getLiquidAmount(params.item.liquid)))
+    else
+        return success(warehouse:getItemCount(params.item.aircraft or params.item.weapon))
+    end
+end
+
+function method_warehouseAddItem(params)
+    -- TODO: return error on missing params
+    local warehouse = warehouseByIdentifier(params)
+    if warehouse == nil then
+        return error("Airbase does not exist")
+    end
+
+    if params.item.liquid ~= nil then
+        warehouse:addLiquid(params.item.liquid, params.amount)
+    else
+        warehouse:addItem(params.item.aircraft or params.item.weapon, params.amount)
+    end
+    return success(nil)
+end
+
+function method_warehouseRemoveItem(params)
+    -- TODO: return error on missing params
+    local warehouse = warehouseByIdentifier(params)
+    if warehouse == nil then
+        return error("Airbase does not exist")
+    end
+
+    if params.item.liquid ~= nil then
+        warehouse:removeLiquid(params.item.liquid, params.amount)
+    else
+        warehouse:removeItem(params.item.aircraft or params.item.weapon, params.amount)
+    end
+    return success(nil)
+end
+
+function method_warehouseSetItem(params)
+    -- TODO: return error on missing params
+    local warehouse = warehouseByIdentifier(params)
+    if warehouse == nil then
+        return error("Airbase does not exist")
+    end
+
+    if params.item.liquid ~= nil then
+        warehouse:setLiquidAmount(params.item.liquid, params.amount)
+    else
+        warehouse:setItem(params.item.aircraft or params.item.weapon, params.amount)
+    end
+    return success(nil)
 end
 
 --
@@ -576,10 +1700,14 @@ end
 
 function method_addStatic(params)
     -- TODO: return error on missing params
-    coalition.addStaticObject(params.country, params.data)
-    -- Note: the static does not exist immediately, why we cannot do something like
-    -- staticobj:getName() here
-    return success(nil)
+    local staticobj = coalition.addStaticObject(params.country, params.data)
+    if staticobj == nil then
+        return error("Failed to add static "..tostring(params.data.name))
+    end
+    return success({
+        name = staticobj:getName(),
+        id = tonumber(staticobj:getID()),
+    })
 end
 
 function method_staticID(params)
@@ -619,12 +1747,7 @@ function method_staticData(params)
         return success(nil)
     end
 
-    local countries = {}
-    if staticobj:getCoalition() == coalition.side.RED then
-        countries = env.mission.coalition.red.country
-    else
-        countries = env.mission.coalition.blue.country
-    end
+    local countries = coalition_countries(staticobj:getCoalition())
 
     local id = tonumber(staticobj:getID())
     for _, country in pairs(countries) do
@@ -677,6 +1800,145 @@ function method_staticDestory(params)
     return success(nil)
 end
 
+--
+-- RPC World methods
+--
+
+function volume_from_json(volume)
+    if volume.kind == "sphere" then
+        return {
+            id = world.VolumeType.SPHERE,
+            params = { point = volume.center, radius = volume.radius },
+        }
+    elseif volume.kind == "box" then
+        return {
+            id = world.VolumeType.BOX,
+            params = { min = volume.min, max = volume.max },
+        }
+    elseif volume.kind == "pyramid" then
+        return {
+            id = world.VolumeType.PYRAMID,
+            params = {
+                pos = volume.orientation,
+                length = volume.length,
+                halfAngleHor = volume.halfAngleHorizontal,
+                halfAngleVer = volume.halfAngleVertical,
+            },
+        }
+    elseif volume.kind == "segment" then
+        return {
+            id = world.VolumeType.SEGMENT,
+            params = { from = volume.from, to = volume.to },
+        }
+    else
+        return nil
+    end
+end
+
+function method_searchObjects(params)
+    -- TODO: return error on missing params
+    local volume = volume_from_json(params.volume)
+    if volume == nil then
+        return error("Unknown volume kind")
+    end
+
+    -- search each category on its own to know the category of the found objects, as e.g.
+    -- unit:getCategory() returns the unit category instead of the object category
+    local objects = {}
+    for _, category in ipairs(params.categories) do
+        world.searchObjects(category, volume, function(obj)
+            if category == Object.Category.WEAPON then
+                remember_weapon(obj)
+            end
+            table.insert(objects, object_identifier(obj, category))
+            return true
+        end)
+    end
+    return success(objects)
+end
+
+--
+-- RPC Land methods
+--
+
+function method_landHeight(params)
+    -- TODO: return error on missing params
+    return success(land.getHeight({ x = params.x, y = params.y }))
+end
+
+function method_landSurfaceType(params)
+    -- TODO: return error on missing params
+    return success(land.getSurfaceType({ x = params.x, y = params.y }))
+end
+
+function method_landIsVisible(params)
+    -- TODO: return error on missing params
+    return success(land.isVisible(params.from, params.to))
+end
+
+function method_landIntersection(params)
+    -- TODO: return error on missing params
+    return success(land.getIP(params.origin, params.direction, params.distance))
+end
+
+function method_landProfile(params)
+    -- TODO: return error on missing params
+    local samples = math.max(params.samples, 2)
+    local profile = {}
+    for i = 0, samples - 1 do
+        local t = i / (samples - 1)
+        local x = params.from.x + (params.to.x - params.from.x) * t
+        local z = params.from.z + (params.to.z - params.from.z) * t
+        table.insert(profile, { x = x, y = land.getHeight({ x = x, y = z }), z = z })
+    end
+    return success(profile)
+end
+
+function method_landFindPath(params)
+    -- TODO: return error on missing params
+    local path = land.findPathOnRoads(
+        params.kind,
+        params.from.x,
+        params.from.z,
+        params.to.x,
+        params.to.z
+    )
+    if path == nil or #path == 0 then
+        return success(nil)
+    end
+
+    local points = {}
+    for _, p in ipairs(path) do
+        table.insert(points, { x = p.x, y = land.getHeight({ x = p.x, y = p.y }), z = p.y })
+    end
+    return success(points)
+end
+
+function method_landClosestPointOnRoads(params)
+    -- TODO: return error on missing params
+    local x, y = land.getClosestPointOnRoads("roads", params.x, params.y)
+    return success({ x = x, y = land.getHeight({ x = x, y = y }), z = y })
+end
+
+--
+-- RPC Atmosphere methods
+--
+
+function method_atmosphereWind(params)
+    -- TODO: return error on missing params
+    if params.withTurbulence then
+        return success(atmosphere.getWindWithTurbulence(params.position))
+    else
+        return success(atmosphere.getWind(params.position))
+    end
+end
+
+function method_atmosphereTemperatureAndPressure(params)
+    -- TODO: return error on missing params
+    local temperature, pressure = atmosphere.getTemperatureAndPressure(params.position)
+    return success({ temperature = temperature, pressure = pressure })
+end
+
 --
 -- RPC Mission Commands methods
 --
@@ -804,6 +2066,233 @@ function handleRequest(method, params)
     end
 end
 
+--
+-- weapon tracking (opt-in via trackWeapons)
+--
+weaponTracking = false
+trackedWeapons = {}
+-- the max. distance in m between a disappearing weapon and its target to count as an impact
+targetImpactRadius = 50
+
+function track_weapon(weapon)
+    local id = weapon_id(weapon)
+    if id == nil then
+        return
+    end
+
+    local target = weapon:getTarget()
+    local tracked = {
+        weapon = weapon,
+        typeName = weapon:getTypeName(),
+        initiator = identifier(weapon:getLauncher()),
+        shotAt = timer.getTime(),
+        pos = weapon:getPoint(),
+        vel = weapon:getVelocity(),
+    }
+    -- identify the target right away, as it might not exist anymore once the weapon impacted
+    if target ~= nil then
+        tracked.target = target
+        tracked.targetIdentifier = object_identifier(target)
+        tracked.targetPos = target:getPoint()
+    end
+    trackedWeapons[id] = tracked
+end
+
+function distance(a, b)
+    local dx, dy, dz = a.x - b.x, a.y - b.y, a.z - b.z
+    return math.sqrt(dx * dx + dy * dy + dz * dz)
+end
+
+-- find the terrain intersection along the weapon's last known velocity (a weapon can travel
+-- quite some distance between two ticks)
+function weapon_impact(tracked)
+    local speed = math.sqrt(tracked.vel.x ^ 2 + tracked.vel.y ^ 2 + tracked.vel.z ^ 2)
+    if speed > 0 then
+        local dir = {
+            x = tracked.vel.x / speed,
+            y = tracked.vel.y / speed,
+            z = tracked.vel.z / speed,
+        }
+        local ip = land.getIP(tracked.pos, dir, speed * 0.1)
+        if ip ~= nil then
+            return ip
+        end
+    end
+
+    -- close to the ground, but the velocity does not point towards it
+    local agl = tracked.pos.y - land.getHeight({ x = tracked.pos.x, y = tracked.pos.z })
+    if agl < 10 then
+        return tracked.pos
+    end
+
+    return nil
+end
+
+function track_weapons()
+    local now = timer.getTime()
+    for id, tracked in pairs(trackedWeapons) do
+        if tracked.weapon:isExist() then
+            tracked.pos = tracked.weapon:getPoint()
+            tracked.vel = tracked.weapon:getVelocity()
+            if tracked.target ~= nil and tracked.target:isExist() then
+                tracked.targetPos = tracked.target:getPoint()
+            end
+        else
+            trackedWeapons[id] = nil
+
+            local impact = weapon_impact(tracked)
+            local position = impact or tracked.pos
+            local targetDistance = nil
+            if tracked.targetPos ~= nil then
+                targetDistance = distance(position, tracked.targetPos)
+                -- weapons that disappear close to their target (e.g. missiles detonating next to
+                -- an aircraft) hit it
+                if impact == nil and targetDistance <= targetImpactRadius then
+                    impact = position
+                end
+            end
+
+            local name = "WeaponGone"
+            if impact ~= nil then
+                name = "WeaponImpact"
+            end
+            jsonrpc.broadcast(name, json:encode({
+                time = now,
+                initiator = tracked.initiator,
+                weapon = { id = id },
+                weaponType = tracked.typeName,
+                position = position,
+                target = tracked.targetIdentifier,
+                targetDistance = targetDistance,
+                flightTime = now - tracked.shotAt,
+            }))
+        end
+    end
+end
+
+--
+-- zone watches (opt-in via watchZone)
+--
+zoneWatches = {}
+nextZoneWatchId = 1
+lastZoneCheck = 0
+
+-- whether the position (vec3) is inside the mission editor zone (ignoring its altitude)
+function zone_contains(zone, p)
+    if zone.type == 2 and zone.verticies ~= nil then
+        local inside = false
+        local j = #zone.verticies
+        for i = 1, #zone.verticies do
+            local a, b = zone.verticies[i], zone.verticies[j]
+            if (a.y > p.z) ~= (b.y > p.z) and p.x < (b.x - a.x) * (p.z - a.y) / (b.y - a.y) + a.x then
+                inside = not inside
+            end
+            j = i
+        end
+        return inside
+    else
+        return (p.x - zone.x) ^ 2 + (p.z - zone.y) ^ 2 <= zone.radius ^ 2
+    end
+end
+
+function watched_units(filter)
+    local units = {}
+    if filter.units ~= nil then
+        for _, name in ipairs(filter.units) do
+            local unit = Unit.getByName(name)
+            if unit ~= nil then
+                table.insert(units, unit)
+            end
+        end
+    elseif filter.groups ~= nil then
+        for _, name in ipairs(filter.groups) do
+            local group = Group.getByName(name)
+            if group ~= nil then
+                for _, unit in ipairs(group:getUnits()) do
+                    table.insert(units, unit)
+                end
+            end
+        end
+    else
+        local sides = { coalition.side.NEUTRAL, coalition.side.RED, coalition.side.BLUE }
+        if filter.coalition ~= nil then
+            sides = { filter.coalition }
+        end
+        for _, side in ipairs(sides) do
+            for _, group in ipairs(coalition.getGroups(side, filter.category)) do
+                for _, unit in ipairs(group:getUnits()) do
+                    table.insert(units, unit)
+                end
+            end
+        end
+    end
+
+    local matching = {}
+    for _, unit in ipairs(units) do
+        if unit:isExist() and unit:isActive()
+            and (filter.coalition == nil or unit:getCoalition() == filter.coalition)
+            and (filter.category == nil or unit:getGroup():getCategory() == filter.category)
+            and (not filter.playersOnly or unit:getPlayerName() ~= nil)
+        then
+            table.insert(matching, unit)
+        end
+    end
+    return matching
+end
+
+function check_zone_watches()
+    local now = timer.getTime()
+    for id, watch in pairs(zoneWatches) do
+        local inside = {}
+        for _, unit in ipairs(watched_units(watch.filter)) do
+            local name = unit:getName()
+            if zone_contains(watch.zone, unit:getPoint()) then
+                inside[name] = true
+                if not watch.inside[name] then
+                    jsonrpc.broadcast("ZoneEnter", json:encode({
+                        time = now,
+                        watch = id,
+                        zone = watch.zone.name,
+                        initiator = name,
+                    }))
+                end
+            end
+        end
+
+        for name, _ in pairs(watch.inside) do
+            if not inside[name] then
+                jsonrpc.broadcast("ZoneLeave", json:encode({
+                    time = now,
+                    watch = id,
+                    zone = watch.zone.name,
+                    initiator = name,
+                }))
+            end
+        end
+        watch.inside = inside
+    end
+end
+
+--
+-- flag watches (opt-in via watchFlags)
+--
+watchedFlags = {}
+
+function check_flags()
+    for flag, old in pairs(watchedFlags) do
+        local new = trigger.misc.getUserFlag(flag)
+        if new ~= old then
+            watchedFlags[flag] = new
+            jsonrpc.broadcast("FlagChanged", json:encode({
+                time = timer.getTime(),
+                flag = flag,
+                old = old,
+                new = new,
+            }))
+        end
+    end
+end
+
 --
 -- execute JSON-RPC requests every 0.02 seconds
 --
@@ -824,6 +2313,27 @@ timer.scheduleFunction(function()
         env.error("[JSONRPC] Error retrieving next command: "..tostring(err))
     end
 
+    if weaponTracking then
+        local ok, err = pcall(track_weapons)
+        if not ok then
+            env.error("[JSONRPC] Error tracking weapons: "..tostring(err))
+        end
+    end
+
+    local ok, err = pcall(check_flags)
+    if not ok then
+        env.error("[JSONRPC] Error checking flags: "..tostring(err))
+    end
+
+    -- check zone watches once per second
+    if timer.getTime() - lastZoneCheck >= 1 then
+        lastZoneCheck = timer.getTime()
+        local ok, err = pcall(check_zone_watches)
+        if not ok then
+            env.error("[JSONRPC] Error checking zone watches: "..tostring(err))
+        end
+    end
+
     return timer.getTime() + .02 -- return time of next call
 end, nil, timer.getTime() + .02)
 
@@ -837,6 +2347,27 @@ function identifier(obj)
     return obj:getName()
 end
 
+-- identifies an object of any category (unit, weapon, static, scenery, base or cargo); the
+-- object category is looked up via Object.getCategory unless given, as e.g. unit:getCategory()
+-- returns the unit category instead
+function object_identifier(obj, category)
+    if category == nil then
+        category = Object.getCategory(obj)
+    end
+    local id
+    if category == Object.Category.WEAPON then
+        id = weapon_id(obj)
+    else
+        id = tonumber(obj:getID())
+    end
+
+    return {
+        id = id,
+        name = obj:getName() or "",
+        category = category,
+    }
+end
+
 function onEvent(event)
     --env.info("[JSONRPC] Event: "..inspect(event))
 
@@ -844,22 +2375,22 @@ function onEvent(event)
         env.info("[JSONRPC] Event: ignoring event (id: "..tostring(event.id)..") with missing initiator")
 
     elseif event.id == world.event.S_EVENT_SHOT then
+        remember_weapon(event.weapon)
+        if weaponTracking then
+            track_weapon(event.weapon)
+        end
         jsonrpc.broadcast("Shot", json:encode({
             time = event.time,
             initiator = identifier(event.initiator),
-            weapon = { id = event.weapon:getName() },
+            weapon = { id = weapon_id(event.weapon) },
         }))
 
     elseif event.id == world.event.S_EVENT_HIT then
         if event.target ~= nil then
-            local target = {
-                id = tonumber(event.target:getID()),
-                name = event.target:getName() or "",
-                category = event.target:getCategory(),
-            }
+            local target = object_identifier(event.target)
             local weapon = nil
             if event.weapon ~= nil then
-                weapon = { id = event.weapon:getName() }
+                weapon = { id = weapon_id(event.weapon) }
             end
             jsonrpc.broadcast("Hit", json:encode({
                 time = event.time,